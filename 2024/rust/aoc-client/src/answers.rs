@@ -0,0 +1,129 @@
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::{day_dir, Part};
+
+/// Known correct answers of a day, kept in an `answers.toml` next to the cached input.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub a: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub b: Option<String>,
+}
+
+/// How an output compares to the known answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verification {
+    Match,
+    Mismatch { expected: String },
+    Unknown,
+}
+impl fmt::Display for Verification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verification::Match => write!(f, "ok"),
+            Verification::Mismatch { expected } => write!(f, "MISMATCH, expected {expected}"),
+            Verification::Unknown => write!(f, "unknown answer"),
+        }
+    }
+}
+
+impl Answers {
+    #[must_use]
+    pub fn path(cache_dir: &Path, year: u16, day: u8) -> PathBuf {
+        day_dir(cache_dir, year, day).join("answers.toml")
+    }
+
+    /// Reads the answers, none if the file doesn't exist yet.
+    ///
+    /// # Errors
+    /// If the file can't be read or isn't valid.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content).with_context(|| format!("Parse {path:?}")),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err).context(format!("Read {path:?}")),
+        }
+    }
+
+    /// # Errors
+    /// If the file can't be written.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, toml::to_string(self)?).with_context(|| format!("Write {path:?}"))
+    }
+
+    #[must_use]
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::A => self.a.as_deref(),
+            Part::B => self.b.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: impl Into<String>) {
+        let answer = Some(answer.into());
+        match part {
+            Part::A => self.a = answer,
+            Part::B => self.b = answer,
+        }
+    }
+
+    #[must_use]
+    pub fn verify(&self, part: Part, output: &str) -> Verification {
+        match self.get(part) {
+            Some(expected) if expected == output.trim() => Verification::Match,
+            Some(expected) => Verification::Mismatch {
+                expected: expected.to_string(),
+            },
+            None => Verification::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test]
+    fn roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = Answers::path(dir.path(), 2024, 7);
+        assert_eq!(Answers::default(), Answers::load(&path).unwrap());
+        let mut answers = Answers::default();
+        answers.set(Part::A, "3749");
+        answers.save(&path).unwrap();
+        assert_eq!("a = \"3749\"\n", fs::read_to_string(&path).unwrap());
+        answers.set(Part::B, "11387");
+        answers.save(&path).unwrap();
+        assert_eq!(answers, Answers::load(&path).unwrap());
+    }
+
+    #[test]
+    fn invalid_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("answers.toml");
+        fs::write(&path, "a = 3749").unwrap();
+        assert!(Answers::load(&path).is_err());
+    }
+
+    #[test_case(Part::A, "3749\n" => Verification::Match)]
+    #[test_case(Part::A, "3748" => Verification::Mismatch { expected: "3749".to_string() })]
+    #[test_case(Part::B, "11387" => Verification::Unknown)]
+    fn verify(part: Part, output: &str) -> Verification {
+        let answers = Answers {
+            a: Some("3749".to_string()),
+            b: None,
+        };
+        answers.verify(part, output)
+    }
+}
@@ -0,0 +1,343 @@
+//! The client API without async, for callers that don't want to pull in an async runtime (e.g. the
+//! day binaries and benches).
+//!
+//! The functions run the async ones of the crate with a [`Ureq`] transport, blocking the thread
+//! until they finish.
+
+use std::{
+    future::Future,
+    io::Read,
+    path::PathBuf,
+    pin::pin,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread},
+    time::Duration,
+};
+
+use crate::{
+    AocClientError, Backoff, Client, Config, Leaderboard, Method, Part, Puzzle, Request,
+    SubmissionResult, Transport,
+};
+
+/// Blocking [`Transport`] with a `ureq` agent.
+#[derive(Debug, Clone)]
+pub struct Ureq(ureq::Agent);
+impl Default for Ureq {
+    fn default() -> Self {
+        Self(ureq::Agent::new())
+    }
+}
+impl Transport for Ureq {
+    async fn send(&self, request: Request) -> Result<(u16, String), AocClientError> {
+        let method = match request.method {
+            Method::Get => "GET",
+            Method::Post => "POST",
+        };
+        let mut builder = self.0.request(method, &request.url);
+        for (name, value) in &request.headers {
+            builder = builder.set(name, value);
+        }
+        let response = if request.form.is_empty() {
+            builder.call()
+        } else {
+            let form: Vec<_> = request
+                .form
+                .iter()
+                .map(|(name, value)| (*name, value.as_str()))
+                .collect();
+            builder.send_form(&form)
+        };
+        let response = match response {
+            Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+            Err(ureq::Error::Transport(err)) => return Err(err.into()),
+        };
+        let status = response.status();
+        let mut body = String::new();
+        response.into_reader().read_to_string(&mut body)?;
+        Ok((status, body))
+    }
+
+    async fn sleep(duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// Blocking [`crate::AocClient`].
+pub type AocClient = Client<Ureq>;
+
+// wakes the thread parked in `block_on`
+struct ThreadWaker(Thread);
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+// runs the future on the current thread, parking it while the future is pending (the `Ureq`
+// transport itself never suspends)
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+    let mut future = pin!(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+        thread::park();
+    }
+}
+
+/// Blocking [`crate::get_input`].
+#[allow(clippy::missing_errors_doc)]
+#[tracing::instrument]
+pub fn get_input(dir: PathBuf, year: Option<u16>, day: u8) -> Result<String, AocClientError> {
+    let config = Config::discover_from(&dir)?;
+    get_input_with(&config.blocking_client(), &config, year, day)
+}
+
+/// Blocking [`crate::get_input_with`].
+#[allow(clippy::missing_errors_doc)]
+pub fn get_input_with(
+    client: &AocClient,
+    config: &Config,
+    year: Option<u16>,
+    day: u8,
+) -> Result<String, AocClientError> {
+    block_on(crate::get_input_with(client, config, year, day))
+}
+
+/// Blocking [`crate::wait_for_input`].
+#[allow(clippy::missing_errors_doc)]
+#[tracing::instrument]
+pub fn wait_for_input(
+    dir: PathBuf,
+    year: Option<u16>,
+    day: u8,
+    backoff: Backoff,
+) -> Result<String, AocClientError> {
+    let config = Config::discover_from(&dir)?;
+    wait_for_input_with(&config.blocking_client(), &config, year, day, backoff)
+}
+
+/// Blocking [`crate::wait_for_input_with`].
+#[allow(clippy::missing_errors_doc)]
+pub fn wait_for_input_with(
+    client: &AocClient,
+    config: &Config,
+    year: Option<u16>,
+    day: u8,
+    backoff: Backoff,
+) -> Result<String, AocClientError> {
+    block_on(crate::wait_for_input_with(
+        client, config, year, day, backoff,
+    ))
+}
+
+/// Blocking [`crate::submit_answer`].
+#[allow(clippy::missing_errors_doc)]
+pub fn submit_answer(
+    year: Option<u16>,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> anyhow::Result<SubmissionResult> {
+    let config = Config::discover()?;
+    submit_answer_with(&config.blocking_client(), &config, year, day, part, answer)
+}
+
+/// Blocking [`crate::submit_answer_with`].
+#[allow(clippy::missing_errors_doc)]
+pub fn submit_answer_with(
+    client: &AocClient,
+    config: &Config,
+    year: Option<u16>,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> anyhow::Result<SubmissionResult> {
+    block_on(crate::submit_answer_with(
+        client, config, year, day, part, answer,
+    ))
+}
+
+/// Blocking [`crate::download_puzzle`].
+#[allow(clippy::missing_errors_doc)]
+pub fn download_puzzle(year: Option<u16>, day: u8) -> anyhow::Result<Puzzle> {
+    let config = Config::discover()?;
+    download_puzzle_with(&config.blocking_client(), &config, year, day)
+}
+
+/// Blocking [`crate::download_puzzle_with`].
+#[allow(clippy::missing_errors_doc)]
+pub fn download_puzzle_with(
+    client: &AocClient,
+    config: &Config,
+    year: Option<u16>,
+    day: u8,
+) -> anyhow::Result<Puzzle> {
+    block_on(crate::download_puzzle_with(client, config, year, day))
+}
+
+/// Blocking [`crate::fetch_leaderboard`].
+#[allow(clippy::missing_errors_doc)]
+pub fn fetch_leaderboard(year: Option<u16>, id: Option<u64>) -> anyhow::Result<Leaderboard> {
+    let config = Config::discover()?;
+    fetch_leaderboard_with(&config.blocking_client(), &config, year, id)
+}
+
+/// Blocking [`crate::fetch_leaderboard_with`].
+#[allow(clippy::missing_errors_doc)]
+pub fn fetch_leaderboard_with(
+    client: &AocClient,
+    config: &Config,
+    year: Option<u16>,
+    id: Option<u64>,
+) -> anyhow::Result<Leaderboard> {
+    block_on(crate::fetch_leaderboard_with(client, config, year, id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{input_path, Answers, USER_AGENT};
+    use std::time::Instant;
+    use wiremock::{
+        matchers::{body_string, header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    async fn server(status: u16, body: &str) -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(status).set_body_string(body))
+            .mount(&server)
+            .await;
+        server
+    }
+
+    fn client(server: &MockServer) -> AocClient {
+        AocClient::new("abc")
+            .with_base_url(server.uri())
+            .with_throttle(Duration::ZERO)
+    }
+
+    #[tokio::test]
+    async fn get_input_once() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2024/day/3/input"))
+            .and(header("Cookie", "session=abc"))
+            .and(header("User-Agent", USER_AGENT))
+            .respond_with(ResponseTemplate::new(200).set_body_string("mul(2,4)\n"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config::new(dir.path());
+        for _ in 0..2 {
+            let input = get_input_with(&client(&server), &config, Some(2024), 3);
+            assert_eq!("mul(2,4)\n", input.unwrap());
+        }
+        assert!(input_path(&config.cache_dir, 2024, 3).exists());
+    }
+
+    #[tokio::test]
+    async fn error_status() {
+        let body = "Please don't repeatedly request this endpoint before it unlocks! ...";
+        let server = server(404, body).await;
+        let err = client(&server).fetch_input(2030, 1).unwrap_err();
+        assert!(matches!(
+            err,
+            AocClientError::NotUnlocked { year: 2030, day: 1 }
+        ));
+    }
+
+    #[tokio::test]
+    async fn wait_and_retry() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404).set_body_string("... before it unlocks!"))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("1\n"))
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config::new(dir.path());
+        let backoff = Backoff {
+            initial: Duration::from_millis(10),
+            ..Backoff::default()
+        };
+        let input = wait_for_input_with(&client(&server), &config, Some(2024), 7, backoff);
+        assert_eq!("1\n", input.unwrap());
+    }
+
+    #[test]
+    fn pending_future() {
+        // pending until another thread wakes it
+        let mut woken = false;
+        let future = std::future::poll_fn(|context| {
+            if woken {
+                return Poll::Ready(7);
+            }
+            woken = true;
+            let waker = context.waker().clone();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(10));
+                waker.wake();
+            });
+            Poll::Pending
+        });
+        assert_eq!(7, block_on(future));
+    }
+
+    #[test]
+    fn unreachable() {
+        let client = AocClient::new("abc").with_base_url("http://127.0.0.1:9");
+        let err = client.fetch_input(2024, 1).unwrap_err();
+        assert!(matches!(err, AocClientError::Http(_)));
+    }
+
+    #[tokio::test]
+    async fn check_token() {
+        let page = r#"<div class="user">elf <span class="star-count">34*</span></div>"#;
+        let server = server(200, page).await;
+        assert_eq!("elf", client(&server).check_token().unwrap());
+    }
+
+    #[tokio::test]
+    async fn submit() {
+        let server = MockServer::start().await;
+        let page = "<article><p>That's the right answer!</p></article>";
+        Mock::given(method("POST"))
+            .and(path("/2024/day/7/answer"))
+            .and(body_string("level=2&answer=11387"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(page))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config::new(dir.path());
+        for _ in 0..2 {
+            let res =
+                submit_answer_with(&client(&server), &config, Some(2024), 7, Part::B, "11387");
+            assert_eq!(SubmissionResult::Correct, res.unwrap());
+        }
+        let answers = Answers::load(&Answers::path(&config.cache_dir, 2024, 7)).unwrap();
+        assert_eq!(Some("11387"), answers.get(Part::B));
+    }
+
+    #[tokio::test]
+    async fn throttle() {
+        let server = server(200, "1").await;
+        let client = client(&server).with_throttle(Duration::from_millis(200));
+        let start = Instant::now();
+        for day in 1..=3 {
+            client.clone().fetch_input(2024, day).unwrap();
+        }
+        assert!(start.elapsed() >= Duration::from_millis(400));
+    }
+}
@@ -0,0 +1,303 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use sha2::{Digest, Sha256};
+
+use crate::{day_dir, input_path, last_day, validate_input, AocClientError};
+
+/// An input in the cache.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedInput {
+    pub year: u16,
+    pub day: u8,
+    pub path: PathBuf,
+}
+impl CachedInput {
+    /// The SHA-256 of the input, as lowercase hex.
+    ///
+    /// # Errors
+    /// If the input can't be read.
+    pub fn checksum(&self) -> Result<String, AocClientError> {
+        let input = fs::read(&self.path)?;
+        Ok(format!("{:x}", Sha256::digest(input)))
+    }
+}
+
+/// The puzzle inputs cached in a dir, with a `{year}/day-{day}/input.txt` file per day.
+#[derive(Debug, Clone)]
+pub struct InputCache {
+    dir: PathBuf,
+}
+impl InputCache {
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// The cached input of the day, none if it isn't cached (or empty).
+    ///
+    /// # Errors
+    /// If the cached file can't be read.
+    pub fn read(&self, year: u16, day: u8) -> Result<Option<String>, AocClientError> {
+        match fs::read_to_string(input_path(&self.dir, year, day)) {
+            Ok(input) if input.is_empty() => Ok(None),
+            Ok(input) => Ok(Some(input)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Caches the input of the day.
+    ///
+    /// # Errors
+    /// If the input can't be written.
+    pub fn write(&self, year: u16, day: u8, input: &str) -> Result<PathBuf, AocClientError> {
+        fs::create_dir_all(day_dir(&self.dir, year, day))?;
+        let path = input_path(&self.dir, year, day);
+        fs::write(&path, input)?;
+        Ok(path)
+    }
+
+    /// The cached inputs, by year and day.
+    ///
+    /// # Errors
+    /// If the cache dir can't be read.
+    pub fn list(&self) -> Result<Vec<CachedInput>, AocClientError> {
+        let mut inputs = Vec::new();
+        for (year, year_dir) in numbered_entries(&self.dir, "")? {
+            let Ok(year) = u16::try_from(year) else {
+                continue;
+            };
+            for (day, _) in numbered_entries(&year_dir, "day-")? {
+                let Ok(day) = u8::try_from(day) else {
+                    continue;
+                };
+                let path = input_path(&self.dir, year, day);
+                if path.is_file() {
+                    inputs.push(CachedInput { year, day, path });
+                }
+            }
+        }
+        inputs.sort_by_key(|input| (input.year, input.day));
+        Ok(inputs)
+    }
+
+    /// Removes the cached inputs of the year, or only of the `day`, returns the removed files.
+    ///
+    /// The answers and submissions of the days are kept.
+    ///
+    /// # Errors
+    /// If a cached input can't be removed.
+    pub fn purge(&self, year: u16, day: Option<u8>) -> Result<Vec<PathBuf>, AocClientError> {
+        let mut removed = Vec::new();
+        for input in self.list()? {
+            if input.year == year && day.is_none_or(|day| day == input.day) {
+                fs::remove_file(&input.path)?;
+                removed.push(input.path);
+            }
+        }
+        Ok(removed)
+    }
+
+    /// Copies the inputs in `dir` into the cache as inputs of the `year`.
+    ///
+    /// The day is taken from the number in the file name (e.g. `day-01.txt` or `7`) or, for
+    /// `input.txt` files in sub dirs, from the number in the dir name (e.g. `day-7/input.txt`).
+    /// Cached inputs are only overwritten when `overwrite` is set.
+    ///
+    /// # Errors
+    /// An [`AocClientError::InvalidInput`] if a file isn't a puzzle input, nothing is imported then.
+    pub fn import(
+        &self,
+        dir: &Path,
+        year: u16,
+        overwrite: bool,
+    ) -> Result<Vec<CachedInput>, AocClientError> {
+        let mut found = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            let file = if path.is_dir() {
+                path.join("input.txt")
+            } else {
+                path.clone()
+            };
+            let name = path.file_stem().and_then(|name| name.to_str());
+            let Some(day) = name.and_then(|name| day_number(name, year)) else {
+                tracing::debug!(?path, "no day in the name, skipped");
+                continue;
+            };
+            if file.is_file() {
+                found.push((day, file));
+            }
+        }
+        let mut imported = Vec::new();
+        for (day, file) in found {
+            let input = fs::read_to_string(&file)?;
+            validate_input(year, day, &input).map_err(|err| {
+                let reason = match err {
+                    AocClientError::InvalidInput(reason) => reason,
+                    err => err.to_string(),
+                };
+                AocClientError::InvalidInput(format!("{file:?}: {reason}"))
+            })?;
+            let path = input_path(&self.dir, year, day);
+            if overwrite || !path.exists() {
+                imported.push((CachedInput { year, day, path }, input));
+            } else {
+                tracing::info!(?path, "already cached, skipped");
+            }
+        }
+        for (cached, input) in &imported {
+            self.write(year, cached.day, input)?;
+        }
+        let mut imported: Vec<_> = imported.into_iter().map(|(cached, _)| cached).collect();
+        imported.sort_by_key(|input| input.day);
+        Ok(imported)
+    }
+}
+
+// sub dirs named `{prefix}{number}`, none if the dir doesn't exist
+fn numbered_entries(dir: &Path, prefix: &str) -> Result<Vec<(u64, PathBuf)>, AocClientError> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+    let mut dirs = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        let name = entry.file_name();
+        let number = name
+            .to_str()
+            .and_then(|name| name.strip_prefix(prefix)?.parse().ok());
+        if let Some(number) = number.filter(|_| entry.path().is_dir()) {
+            dirs.push((number, entry.path()));
+        }
+    }
+    Ok(dirs)
+}
+
+// the first number in the name, if it's a day of the event of the year
+fn day_number(name: &str, year: u16) -> Option<u8> {
+    let start = name.find(|c: char| c.is_ascii_digit())?;
+    let digits = &name[start..];
+    let end = digits
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(digits.len());
+    digits[..end]
+        .parse()
+        .ok()
+        .filter(|day| (1..=last_day(year)).contains(day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn cache_with(inputs: &[(u16, u8, &str)]) -> (tempfile::TempDir, InputCache) {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = InputCache::new(dir.path().join("inputs"));
+        for (year, day, input) in inputs {
+            let path = input_path(cache.dir(), *year, *day);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, input).unwrap();
+        }
+        (dir, cache)
+    }
+
+    #[test_case("day-01", 2024 => Some(1))]
+    #[test_case("day7", 2024 => Some(7))]
+    #[test_case("25", 2024 => Some(25))]
+    #[test_case("input-12-b", 2025 => Some(12))]
+    #[test_case("day-26", 2024 => None)]
+    #[test_case("day-13", 2025 => None)]
+    #[test_case("input", 2024 => None)]
+    fn day(name: &str, year: u16) -> Option<u8> {
+        day_number(name, year)
+    }
+
+    #[test]
+    fn list() {
+        let (_dir, cache) = cache_with(&[(2024, 10, "a"), (2023, 2, "b"), (2024, 9, "c")]);
+        fs::create_dir_all(cache.dir().join("2024/day-11")).unwrap();
+        fs::create_dir_all(cache.dir().join("notes")).unwrap();
+        let days: Vec<_> = cache
+            .list()
+            .unwrap()
+            .iter()
+            .map(|input| (input.year, input.day))
+            .collect();
+        assert_eq!(vec![(2023, 2), (2024, 9), (2024, 10)], days);
+        assert!(InputCache::new("/nonexistent").list().unwrap().is_empty());
+    }
+
+    #[test]
+    fn read() {
+        let (_dir, cache) = cache_with(&[(2024, 1, "1 2\n"), (2024, 2, "")]);
+        assert_eq!(Some("1 2\n".to_string()), cache.read(2024, 1).unwrap());
+        assert_eq!(None, cache.read(2024, 2).unwrap());
+        assert_eq!(None, cache.read(2024, 3).unwrap());
+    }
+
+    #[test]
+    fn checksum() {
+        let (_dir, cache) = cache_with(&[(2024, 1, "abc")]);
+        assert_eq!(
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            cache.list().unwrap()[0].checksum().unwrap()
+        );
+    }
+
+    #[test]
+    fn purge() {
+        let (_dir, cache) = cache_with(&[(2024, 1, "a"), (2024, 2, "b"), (2023, 1, "c")]);
+        fs::write(
+            day_dir(cache.dir(), 2024, 1).join("answers.toml"),
+            "a = \"1\"",
+        )
+        .unwrap();
+        assert_eq!(1, cache.purge(2024, Some(1)).unwrap().len());
+        assert!(day_dir(cache.dir(), 2024, 1).join("answers.toml").exists());
+        assert_eq!(1, cache.purge(2024, None).unwrap().len());
+        assert_eq!(1, cache.list().unwrap().len());
+    }
+
+    #[test]
+    fn import() {
+        let (dir, cache) = cache_with(&[(2024, 3, "cached")]);
+        let from = dir.path().join("from");
+        fs::create_dir_all(from.join("day-2")).unwrap();
+        fs::write(from.join("day-01.txt"), "1\n").unwrap();
+        fs::write(from.join("day-2/input.txt"), "2\n").unwrap();
+        fs::write(from.join("day-2/example.txt"), "ex\n").unwrap();
+        fs::write(from.join("3.txt"), "3\n").unwrap();
+        fs::write(from.join("README.md"), "inputs").unwrap();
+        let imported = cache.import(&from, 2024, false).unwrap();
+        assert_eq!(
+            vec![1, 2],
+            imported.iter().map(|i| i.day).collect::<Vec<_>>()
+        );
+        assert_eq!(Some("2\n".to_string()), cache.read(2024, 2).unwrap());
+        assert_eq!(Some("cached".to_string()), cache.read(2024, 3).unwrap());
+        cache.import(&from, 2024, true).unwrap();
+        assert_eq!(Some("3\n".to_string()), cache.read(2024, 3).unwrap());
+    }
+
+    #[test]
+    fn import_invalid() {
+        let (dir, cache) = cache_with(&[]);
+        fs::write(dir.path().join("day-1.txt"), "1\n").unwrap();
+        fs::write(dir.path().join("day-2.txt"), "<!DOCTYPE html>").unwrap();
+        let res = cache.import(dir.path(), 2024, false);
+        assert!(matches!(res, Err(AocClientError::InvalidInput(_))));
+        assert!(cache.list().unwrap().is_empty());
+    }
+}
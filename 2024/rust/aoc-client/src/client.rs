@@ -0,0 +1,503 @@
+use std::{
+    collections::HashMap,
+    env,
+    future::Future,
+    sync::{Arc, Mutex, OnceLock, PoisonError},
+    time::{Duration, Instant},
+};
+
+use crate::{AocClientError, Part};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the client to the server, can be overridden with the `AOC_USER_AGENT` env var
+/// (e.g. to add a contact).
+pub const USER_AGENT: &str = concat!("aoc-client/", env!("CARGO_PKG_VERSION"), " (Rust)");
+
+/// Minimum time between two requests to a server, shared by all clients of the process without a
+/// throttle of their own.
+pub const DEFAULT_THROTTLE: Duration = Duration::from_secs(3);
+
+/// Sends the [`Request`]s of a [`Client`], e.g. async with `reqwest` or blocking with `ureq`.
+pub trait Transport: Default + Clone + Send + Sync {
+    /// Sends the request, returns the status and the body of the response.
+    fn send(
+        &self,
+        request: Request,
+    ) -> impl Future<Output = Result<(u16, String), AocClientError>> + Send;
+
+    /// Waits for the `duration`, e.g. for the throttle.
+    fn sleep(duration: Duration) -> impl Future<Output = ()> + Send;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post,
+}
+
+/// A request built by a [`Client`], for its [`Transport`] to send.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(&'static str, String)>,
+    /// Fields of a url encoded form body, none for requests without a body.
+    pub form: Vec<(&'static str, String)>,
+}
+
+#[derive(Debug)]
+struct Throttle {
+    interval: Duration,
+    last_request: Mutex<Option<Instant>>,
+}
+impl Throttle {
+    fn new(interval: Duration) -> Arc<Self> {
+        Arc::new(Self {
+            interval,
+            last_request: Mutex::new(None),
+        })
+    }
+
+    // the default throttle of the server, shared by the clients of the process
+    fn shared(base_url: &str) -> Arc<Self> {
+        static THROTTLES: OnceLock<Mutex<HashMap<String, Arc<Throttle>>>> = OnceLock::new();
+        let mut throttles = THROTTLES
+            .get_or_init(Mutex::default)
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let throttle = throttles
+            .entry(base_url.to_string())
+            .or_insert_with(|| Self::new(DEFAULT_THROTTLE));
+        Arc::clone(throttle)
+    }
+
+    // reserves the time of the next request, returns how long to wait for it
+    fn reserve(&self) -> Duration {
+        let mut last_request = self
+            .last_request
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let now = Instant::now();
+        let next_request = last_request.map_or(now, |last| (last + self.interval).max(now));
+        *last_request = Some(next_request);
+        next_request - now
+    }
+}
+
+/// Advent of Code HTTP client authenticated with a session token, sending its requests with the
+/// [`Transport`] `T` (see [`AocClient`] and `blocking::AocClient`).
+#[derive(Debug, Clone)]
+pub struct Client<T> {
+    base_url: String,
+    session_token: Option<String>,
+    user_agent: String,
+    // none for the shared throttle of the server
+    throttle: Option<Arc<Throttle>>,
+    transport: T,
+}
+impl<T: Transport> Client<T> {
+    #[must_use]
+    pub fn new(session_token: impl Into<String>) -> Self {
+        Self {
+            base_url: BASE_URL.to_string(),
+            session_token: Some(session_token.into()),
+            user_agent: USER_AGENT.to_string(),
+            throttle: None,
+            transport: T::default(),
+        }
+    }
+
+    /// Client with the `SESSION_TOKEN` env var, requests fail if it isn't set.
+    #[must_use]
+    pub fn from_env() -> Self {
+        let client = Self {
+            session_token: env::var("SESSION_TOKEN").ok(),
+            ..Self::new("")
+        };
+        match env::var("AOC_USER_AGENT") {
+            Ok(user_agent) => client.with_user_agent(user_agent),
+            Err(_) => client,
+        }
+    }
+
+    /// Sends the requests to another server, e.g. a local mock.
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    #[must_use]
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Sets the minimum time between two requests of the client (and its clones), instead of
+    /// sharing the [`DEFAULT_THROTTLE`] of the server with the other clients.
+    #[must_use]
+    pub fn with_throttle(mut self, interval: Duration) -> Self {
+        self.throttle = Some(Throttle::new(interval));
+        self
+    }
+
+    #[must_use]
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Sends the request of the `endpoint` once the throttle allows it.
+    pub(crate) async fn call(&self, endpoint: Endpoint<'_>) -> Result<String, AocClientError> {
+        let request = self.request(endpoint)?;
+        let delay = self.throttle().reserve();
+        if !delay.is_zero() {
+            T::sleep(delay).await;
+        }
+        let (status, body) = self.transport.send(request).await?;
+        endpoint.response(status, body)
+    }
+
+    fn throttle(&self) -> Arc<Throttle> {
+        match &self.throttle {
+            Some(throttle) => Arc::clone(throttle),
+            None => Throttle::shared(&self.base_url),
+        }
+    }
+
+    fn request(&self, endpoint: Endpoint) -> Result<Request, AocClientError> {
+        let session_token = self
+            .session_token
+            .as_ref()
+            .filter(|token| !token.is_empty())
+            .ok_or(AocClientError::MissingToken)?;
+        Ok(Request {
+            method: endpoint.method(),
+            url: format!("{}/{}", self.base_url, endpoint.path()),
+            headers: vec![
+                ("Cookie", format!("session={session_token}")),
+                ("User-Agent", self.user_agent.clone()),
+            ],
+            form: endpoint.form(),
+        })
+    }
+}
+
+// the endpoint methods of the client with the transport, `$run` gets the result of the `call`
+macro_rules! endpoints {
+    ($transport: ty, [$($async: tt)?], |$call: ident| $run: expr) => {
+        impl Client<$transport> {
+            /// Checks the session token is accepted, returns the name of the logged in user.
+            ///
+            /// # Errors
+            /// An [`AocClientError::Unauthorized`] if the server doesn't know the session.
+            #[tracing::instrument(skip(self))]
+            pub $($async)? fn check_token(&self) -> Result<String, AocClientError> {
+                let $call = self.call(Endpoint::User);
+                $run
+            }
+
+            /// Downloads the puzzle input of the day.
+            ///
+            /// # Errors
+            /// An [`AocClientError::InvalidInput`] if the server sent something else than an input.
+            #[tracing::instrument(skip(self))]
+            pub $($async)? fn fetch_input(&self, year: u16, day: u8) -> Result<String, AocClientError> {
+                let $call = self.call(Endpoint::Input { year, day });
+                $run
+            }
+
+            /// Downloads the puzzle page of the day.
+            #[allow(clippy::missing_errors_doc)]
+            #[tracing::instrument(skip(self))]
+            pub $($async)? fn fetch_puzzle(&self, year: u16, day: u8) -> Result<String, AocClientError> {
+                let $call = self.call(Endpoint::Puzzle { year, day });
+                $run
+            }
+
+            /// Downloads the JSON of the private leaderboard `id`.
+            #[allow(clippy::missing_errors_doc)]
+            #[tracing::instrument(skip(self))]
+            pub $($async)? fn fetch_leaderboard(&self, year: u16, id: u64) -> Result<String, AocClientError> {
+                let $call = self.call(Endpoint::Leaderboard { year, id });
+                $run
+            }
+
+            /// Posts the answer of the puzzle part, returns the response page.
+            #[allow(clippy::missing_errors_doc)]
+            #[tracing::instrument(skip(self))]
+            pub $($async)? fn post_answer(
+                &self,
+                year: u16,
+                day: u8,
+                part: Part,
+                answer: &str,
+            ) -> Result<String, AocClientError> {
+                let $call = self.call(Endpoint::Answer { year, day, part, answer });
+                $run
+            }
+        }
+    };
+}
+
+/// Async [`Transport`] with a `reqwest` client.
+#[cfg(feature = "async")]
+#[derive(Debug, Clone, Default)]
+pub struct Reqwest(reqwest::Client);
+#[cfg(feature = "async")]
+impl Transport for Reqwest {
+    async fn send(&self, request: Request) -> Result<(u16, String), AocClientError> {
+        let method = match request.method {
+            Method::Get => reqwest::Method::GET,
+            Method::Post => reqwest::Method::POST,
+        };
+        let mut builder = self.0.request(method, request.url);
+        for (name, value) in request.headers {
+            builder = builder.header(name, value);
+        }
+        if !request.form.is_empty() {
+            builder = builder.form(&request.form);
+        }
+        let response = builder.send().await?;
+        let status = response.status().as_u16();
+        Ok((status, response.text().await?))
+    }
+
+    async fn sleep(duration: Duration) {
+        tokio::time::sleep(duration).await;
+    }
+}
+
+/// The async client.
+#[cfg(feature = "async")]
+pub type AocClient = Client<Reqwest>;
+
+#[cfg(feature = "async")]
+endpoints!(Reqwest, [async], |call| call.await);
+#[cfg(feature = "blocking")]
+endpoints!(crate::blocking::Ureq, [], |call| crate::blocking::block_on(
+    call
+));
+
+/// The requests of the clients.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Endpoint<'a> {
+    User,
+    Input {
+        year: u16,
+        day: u8,
+    },
+    Puzzle {
+        year: u16,
+        day: u8,
+    },
+    Leaderboard {
+        year: u16,
+        id: u64,
+    },
+    Answer {
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &'a str,
+    },
+}
+impl Endpoint<'_> {
+    fn method(self) -> Method {
+        match self {
+            Self::Answer { .. } => Method::Post,
+            _ => Method::Get,
+        }
+    }
+
+    fn path(self) -> String {
+        match self {
+            Self::User => String::new(),
+            Self::Input { year, day } => format!("{year}/day/{day}/input"),
+            Self::Puzzle { year, day } => format!("{year}/day/{day}"),
+            Self::Leaderboard { year, id } => format!("{year}/leaderboard/private/view/{id}.json"),
+            Self::Answer { year, day, .. } => format!("{year}/day/{day}/answer"),
+        }
+    }
+
+    fn form(self) -> Vec<(&'static str, String)> {
+        match self {
+            Self::Answer { part, answer, .. } => vec![
+                ("level", part.level().to_string()),
+                ("answer", answer.to_string()),
+            ],
+            _ => Vec::new(),
+        }
+    }
+
+    // the body of a successful response, validated for the endpoint
+    fn response(self, status: u16, body: String) -> Result<String, AocClientError> {
+        let (year, day) = match self {
+            Self::User => (0, 0),
+            Self::Input { year, day }
+            | Self::Puzzle { year, day }
+            | Self::Answer { year, day, .. } => (year, day),
+            Self::Leaderboard { year, .. } => (year, 0),
+        };
+        let body = check_response(status, body, year, day)?;
+        match self {
+            Self::User => user_name(&body),
+            Self::Input { .. } => validate_input(year, day, &body).map(|()| body),
+            _ => Ok(body),
+        }
+    }
+}
+
+/// The body of a successful response, or the error the response stands for.
+fn check_response(status: u16, body: String, year: u16, day: u8) -> Result<String, AocClientError> {
+    match status {
+        200..=299 => Ok(body),
+        429 => Err(AocClientError::RateLimited),
+        404 if body.contains("before it unlocks") => Err(AocClientError::NotUnlocked { year, day }),
+        400 | 401 | 403 if body.contains("log in") => Err(AocClientError::Unauthorized),
+        status => Err(AocClientError::UnexpectedStatus(status)),
+    }
+}
+
+/// The logged in user of a page.
+fn user_name(page: &str) -> Result<String, AocClientError> {
+    // the header shows `<div class="user">name <span class="star-count">..` when logged in
+    page.split_once("<div class=\"user\">")
+        .and_then(|(_, user)| user.split_once('<'))
+        .map(|(name, _)| name.trim().to_string())
+        .ok_or(AocClientError::Unauthorized)
+}
+
+/// Checks the downloaded input of the day isn't a page or a message of the server.
+///
+/// # Errors
+/// An [`AocClientError::NotUnlocked`] for the message of the server before the unlock, else an
+/// [`AocClientError::InvalidInput`] with the reason.
+pub fn validate_input(year: u16, day: u8, input: &str) -> Result<(), AocClientError> {
+    let start = input.trim_start();
+    let reason = if start.is_empty() {
+        "empty response"
+    } else if start.starts_with("<!DOCTYPE") || start.starts_with("<html") {
+        "HTML page"
+    } else if start.starts_with("Please don't repeatedly request this endpoint") {
+        return Err(AocClientError::NotUnlocked { year, day });
+    } else if start.starts_with("Puzzle inputs differ by user") {
+        "logged out"
+    } else {
+        return Ok(());
+    };
+    Err(AocClientError::InvalidInput(reason.to_string()))
+}
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+    use wiremock::{
+        matchers::{header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    async fn server(status: u16, body: &str) -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(status).set_body_string(body))
+            .mount(&server)
+            .await;
+        server
+    }
+
+    fn client(server: &MockServer) -> AocClient {
+        AocClient::new("abc")
+            .with_base_url(server.uri())
+            .with_throttle(Duration::ZERO)
+    }
+
+    #[tokio::test]
+    async fn fetch_input() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2015/day/1/input"))
+            .and(header("Cookie", "session=abc"))
+            .and(header("User-Agent", USER_AGENT))
+            .respond_with(ResponseTemplate::new(200).set_body_string("(()\n"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = AocClient::new("abc")
+            .with_base_url(server.uri())
+            .with_throttle(Duration::ZERO);
+        assert_eq!("(()\n", client.fetch_input(2015, 1).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn user_agent() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(header("User-Agent", "me@example.com"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("1"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = client(&server).with_user_agent("me@example.com");
+        assert!(client.fetch_input(2015, 1).await.is_ok());
+    }
+
+    #[test_case(404, "Please don't repeatedly request this endpoint before it unlocks! ..." => "NotUnlocked { year: 2030, day: 1 }")]
+    #[test_case(400, "Puzzle inputs differ by user.  Please log in to get your puzzle input." => "Unauthorized")]
+    #[test_case(429, "" => "RateLimited")]
+    #[test_case(404, "Not found" => "UnexpectedStatus(404)")]
+    #[test_case(500, "" => "UnexpectedStatus(500)")]
+    #[test_case(200, "<!DOCTYPE html>\n<html>" => "InvalidInput(\"HTML page\")")]
+    #[test_case(200, "\n" => "InvalidInput(\"empty response\")")]
+    #[test_case(200, "Please don't repeatedly request this endpoint before it unlocks! ..." => "NotUnlocked { year: 2030, day: 1 }")]
+    #[tokio::test]
+    async fn errors(status: u16, body: &str) -> String {
+        let server = server(status, body).await;
+        let err = client(&server).fetch_input(2030, 1).await.unwrap_err();
+        format!("{err:?}")
+    }
+
+    #[tokio::test]
+    async fn check_token() {
+        let page = r#"<header><div><h1 class="title-global"><a href="/">Advent of Code</a></h1>
+            <div class="user">elf <span class="star-count">34*</span></div></div></header>"#;
+        let server = server(200, page).await;
+        assert_eq!("elf", client(&server).check_token().await.unwrap());
+        let page = r#"<div><a href="/2024/auth/login">[Log In]</a></div>"#;
+        let server = self::server(200, page).await;
+        let err = client(&server).check_token().await.unwrap_err();
+        assert!(matches!(err, AocClientError::Unauthorized));
+    }
+
+    #[tokio::test]
+    async fn missing_token() {
+        let client = AocClient::new("").with_base_url("http://127.0.0.1:9");
+        let err = client.fetch_input(2024, 1).await.unwrap_err();
+        assert!(matches!(err, AocClientError::MissingToken));
+    }
+
+    #[test]
+    fn shared_throttle() {
+        let client = AocClient::new("abc");
+        assert!(Arc::ptr_eq(
+            &client.throttle(),
+            &AocClient::new("def").throttle()
+        ));
+        let other_server = AocClient::new("abc").with_base_url("http://127.0.0.1:9");
+        assert!(!Arc::ptr_eq(&client.throttle(), &other_server.throttle()));
+        let own = AocClient::new("abc").with_throttle(DEFAULT_THROTTLE);
+        assert!(!Arc::ptr_eq(&client.throttle(), &own.throttle()));
+    }
+
+    #[tokio::test]
+    async fn throttle() {
+        let server = server(200, "1").await;
+        let client = client(&server).with_throttle(Duration::from_millis(200));
+        let start = std::time::Instant::now();
+        for day in 1..=3 {
+            client.clone().fetch_input(2024, day).await.unwrap();
+        }
+        assert!(start.elapsed() >= Duration::from_millis(400));
+    }
+}
@@ -0,0 +1,382 @@
+use std::{
+    collections::HashMap,
+    env, fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+#[cfg(feature = "async")]
+use crate::AocClient;
+use crate::{infer_year, AocClientError, Client, InputCache, Transport};
+
+/// Where the session token was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenSource {
+    EnvVar,
+    DotEnv(PathBuf),
+    ConfigFile(PathBuf),
+}
+impl fmt::Display for TokenSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenSource::EnvVar => write!(f, "the SESSION_TOKEN env var"),
+            TokenSource::DotEnv(path) | TokenSource::ConfigFile(path) => write!(f, "{path:?}"),
+        }
+    }
+}
+
+// the user's config.toml
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    session_token: Option<String>,
+    cache_dir: Option<PathBuf>,
+    user_agent: Option<String>,
+    offline: Option<bool>,
+    leaderboard: Option<u64>,
+}
+
+/// Settings of the workspace, the session token and the cache.
+///
+/// Each setting is looked up in the env vars, then in the `.env` file of the workspace, then in
+/// the user's `aoc/config.toml` (in `$XDG_CONFIG_HOME` or `~/.config`, or at `$AOC_CONFIG`):
+///
+/// | env var / `.env`  | `config.toml`   | default                |
+/// |-------------------|-----------------|------------------------|
+/// | `SESSION_TOKEN`   | `session_token` |                        |
+/// | `AOC_CACHE_DIR`   | `cache_dir`     | `<root>/inputs`        |
+/// | `AOC_USER_AGENT`  | `user_agent`    | [`crate::USER_AGENT`]  |
+/// | `AOC_OFFLINE`     | `offline`       | `false`                |
+/// | `AOC_LEADERBOARD` | `leaderboard`   |                        |
+///
+/// Relative cache dirs are relative to the workspace root.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// The workspace root, with a `day-{day}` crate per day.
+    pub root: PathBuf,
+    /// Where the inputs (and answers) are cached, with a `{year}/day-{day}` dir per day.
+    pub cache_dir: PathBuf,
+    pub session_token: Option<(String, TokenSource)>,
+    pub user_agent: Option<String>,
+    /// Only read the cached inputs, never download them.
+    pub offline: bool,
+    /// Id of the private leaderboard to show by default.
+    pub leaderboard: Option<u64>,
+}
+impl Config {
+    /// Default settings, without looking anything up.
+    #[must_use]
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        let root = root.into();
+        Self {
+            cache_dir: root.join("inputs"),
+            root,
+            session_token: None,
+            user_agent: None,
+            offline: false,
+            leaderboard: None,
+        }
+    }
+
+    /// Settings of the workspace containing the current dir.
+    ///
+    /// # Errors
+    /// If the current dir is unknown or a config file is invalid.
+    pub fn discover() -> Result<Self, AocClientError> {
+        Self::discover_from(&env::current_dir()?)
+    }
+
+    /// Settings of the workspace containing the `dir` (see [`workspace_root`]).
+    ///
+    /// # Errors
+    /// An [`AocClientError::InvalidConfig`] if a config file is invalid.
+    pub fn discover_from(dir: &Path) -> Result<Self, AocClientError> {
+        Self::load(workspace_root(dir))
+    }
+
+    /// Settings of the workspace `root`.
+    ///
+    /// # Errors
+    /// An [`AocClientError::InvalidConfig`] if a config file is invalid.
+    pub fn load(root: impl Into<PathBuf>) -> Result<Self, AocClientError> {
+        Self::load_from(root.into(), |key| env::var(key).ok(), user_config_path())
+    }
+
+    fn load_from(
+        root: PathBuf,
+        env_var: impl Fn(&str) -> Option<String>,
+        config_path: Option<PathBuf>,
+    ) -> Result<Self, AocClientError> {
+        let dot_env_path = root.join(".env");
+        let dot_env = match fs::read_to_string(&dot_env_path) {
+            Ok(content) => parse_dot_env(&content),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(err) => return Err(err.into()),
+        };
+        let config_file = match &config_path {
+            Some(path) => match fs::read_to_string(path) {
+                Ok(content) => {
+                    toml::from_str(&content).map_err(|err| AocClientError::InvalidConfig {
+                        path: path.clone(),
+                        reason: err.message().to_string(),
+                    })?
+                }
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => ConfigFile::default(),
+                Err(err) => return Err(err.into()),
+            },
+            None => ConfigFile::default(),
+        };
+        let lookup = |key: &str| env_var(key).or_else(|| dot_env.get(key).cloned());
+
+        let session_token = if let Some(token) = env_var("SESSION_TOKEN") {
+            Some((token, TokenSource::EnvVar))
+        } else if let Some(token) = dot_env.get("SESSION_TOKEN") {
+            Some((token.clone(), TokenSource::DotEnv(dot_env_path)))
+        } else {
+            let path = config_path.unwrap_or_default();
+            config_file
+                .session_token
+                .map(|token| (token, TokenSource::ConfigFile(path)))
+        }
+        .filter(|(token, _)| !token.trim().is_empty());
+        let cache_dir = lookup("AOC_CACHE_DIR")
+            .map(PathBuf::from)
+            .or(config_file.cache_dir)
+            .map_or_else(|| root.join("inputs"), |dir| root.join(dir));
+        let user_agent = lookup("AOC_USER_AGENT").or(config_file.user_agent);
+        let offline = lookup("AOC_OFFLINE")
+            .map(|value| !matches!(value.trim(), "" | "0" | "false" | "no"))
+            .or(config_file.offline)
+            .unwrap_or_default();
+        let leaderboard = match lookup("AOC_LEADERBOARD") {
+            Some(id) => Some(
+                id.trim()
+                    .parse()
+                    .map_err(|_| AocClientError::InvalidConfig {
+                        path: "AOC_LEADERBOARD".into(),
+                        reason: format!("'{id}' isn't a leaderboard id"),
+                    })?,
+            ),
+            None => config_file.leaderboard,
+        };
+        Ok(Self {
+            root,
+            cache_dir,
+            session_token,
+            user_agent,
+            offline,
+            leaderboard,
+        })
+    }
+
+    /// Client with the session token and user agent of the config.
+    #[cfg(feature = "async")]
+    #[must_use]
+    pub fn client(&self) -> AocClient {
+        self.client_with()
+    }
+
+    /// [`Config::client`] without async.
+    #[cfg(feature = "blocking")]
+    #[must_use]
+    pub fn blocking_client(&self) -> crate::blocking::AocClient {
+        self.client_with()
+    }
+
+    fn client_with<T: Transport>(&self) -> Client<T> {
+        let token = self.session_token.as_ref().map(|(token, _)| token.trim());
+        let client = Client::new(token.unwrap_or_default());
+        match &self.user_agent {
+            Some(user_agent) => client.with_user_agent(user_agent),
+            None => client,
+        }
+    }
+
+    #[must_use]
+    pub fn cache(&self) -> InputCache {
+        InputCache::new(&self.cache_dir)
+    }
+
+    /// The `year` or the one inferred from the workspace root.
+    ///
+    /// # Errors
+    /// An [`AocClientError::UnknownYear`] if there's no year to infer.
+    pub fn year(&self, year: Option<u16>) -> Result<u16, AocClientError> {
+        match year {
+            Some(year) => Ok(year),
+            None => infer_year(&self.root),
+        }
+    }
+
+    /// The dir of the day crate.
+    #[must_use]
+    pub fn crate_dir(&self, day: u8) -> PathBuf {
+        self.root.join(format!("day-{day}"))
+    }
+}
+
+/// The closest ancestor dir (or the dir itself) with a `[workspace]` Cargo.toml, or the dir if
+/// there's none.
+#[must_use]
+pub fn workspace_root(dir: &Path) -> PathBuf {
+    dir.ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.lines().any(|line| line.trim() == "[workspace]"))
+        })
+        .unwrap_or(dir)
+        .to_path_buf()
+}
+
+fn user_config_path() -> Option<PathBuf> {
+    if let Some(path) = env::var_os("AOC_CONFIG") {
+        return Some(path.into());
+    }
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(config_dir.join("aoc/config.toml"))
+}
+
+// `KEY=value` lines, with optional `export` and quotes
+fn parse_dot_env(content: &str) -> HashMap<String, String> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let line = line.strip_prefix("export ").unwrap_or(line);
+            let (key, value) = line.split_once('=')?;
+            let value = value.trim();
+            let value = ['"', '\'']
+                .iter()
+                .find_map(|quote| value.strip_prefix(*quote)?.strip_suffix(*quote))
+                .unwrap_or(value);
+            Some((key.trim().to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn load(
+        root: &Path,
+        env: &[(&str, &str)],
+        config: Option<&str>,
+    ) -> Result<Config, AocClientError> {
+        let env: HashMap<_, _> = env.iter().copied().collect();
+        let config_path = root.join("config.toml");
+        if let Some(config) = config {
+            fs::write(&config_path, config).unwrap();
+        }
+        Config::load_from(
+            root.to_path_buf(),
+            |key| env.get(key).map(ToString::to_string),
+            Some(config_path),
+        )
+    }
+
+    #[test]
+    fn defaults() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = load(dir.path(), &[], None).unwrap();
+        assert_eq!(dir.path().join("inputs"), config.cache_dir);
+        assert_eq!(None, config.session_token);
+        assert_eq!(None, config.user_agent);
+        assert!(!config.offline);
+    }
+
+    #[test]
+    fn token_precedence() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = Some("session_token = \"from-config\"\ncache_dir = \"/tmp/aoc\"\n");
+        let token = |env: &[(&str, &str)]| load(dir.path(), env, config).unwrap().session_token;
+        assert_eq!(
+            Some((
+                "from-config".into(),
+                TokenSource::ConfigFile(dir.path().join("config.toml"))
+            )),
+            token(&[])
+        );
+        fs::write(
+            dir.path().join(".env"),
+            "# token\nexport SESSION_TOKEN=\"from-dot-env\"\nAOC_USER_AGENT=me\n",
+        )
+        .unwrap();
+        assert_eq!(
+            Some((
+                "from-dot-env".into(),
+                TokenSource::DotEnv(dir.path().join(".env"))
+            )),
+            token(&[])
+        );
+        assert_eq!(
+            Some(("from-env".into(), TokenSource::EnvVar)),
+            token(&[("SESSION_TOKEN", "from-env")])
+        );
+        let config = load(dir.path(), &[], config).unwrap();
+        assert_eq!(PathBuf::from("/tmp/aoc"), config.cache_dir);
+        assert_eq!(Some("me".into()), config.user_agent);
+    }
+
+    #[test]
+    fn relative_cache_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = load(dir.path(), &[("AOC_CACHE_DIR", "cache")], None).unwrap();
+        assert_eq!(dir.path().join("cache"), config.cache_dir);
+    }
+
+    #[test_case(&[("AOC_OFFLINE", "1")], None => true)]
+    #[test_case(&[("AOC_OFFLINE", "false")], Some("offline = true") => false)]
+    #[test_case(&[], Some("offline = true") => true)]
+    fn offline(env: &[(&str, &str)], config: Option<&str>) -> bool {
+        let dir = tempfile::tempdir().unwrap();
+        load(dir.path(), env, config).unwrap().offline
+    }
+
+    #[test]
+    fn leaderboard() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = Some("leaderboard = 123456");
+        assert_eq!(
+            Some(123_456),
+            load(dir.path(), &[], config).unwrap().leaderboard
+        );
+        let env = [("AOC_LEADERBOARD", "42")];
+        assert_eq!(
+            Some(42),
+            load(dir.path(), &env, config).unwrap().leaderboard
+        );
+        let res = load(dir.path(), &[("AOC_LEADERBOARD", "team")], None);
+        assert!(matches!(res, Err(AocClientError::InvalidConfig { .. })));
+    }
+
+    #[test]
+    fn invalid_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let res = load(dir.path(), &[], Some("session = \"abc\""));
+        assert!(matches!(res, Err(AocClientError::InvalidConfig { .. })));
+    }
+
+    #[test]
+    fn workspace() {
+        let dir = tempfile::tempdir().unwrap();
+        let day = dir.path().join("2024/rust/day-1");
+        fs::create_dir_all(&day).unwrap();
+        fs::write(day.join("Cargo.toml"), "[package]\nname = \"day-1\"\n").unwrap();
+        assert_eq!(day, workspace_root(&day));
+        let root = dir.path().join("2024/rust");
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"day-*\"]\n",
+        )
+        .unwrap();
+        assert_eq!(root, workspace_root(&day));
+        assert_eq!(2024, Config::new(workspace_root(&day)).year(None).unwrap());
+        assert_eq!(root, load(&workspace_root(&day), &[], None).unwrap().root);
+    }
+}
@@ -0,0 +1,106 @@
+use std::{fmt, io, path::PathBuf};
+
+use crate::{last_day, FIRST_YEAR};
+
+#[derive(Debug)]
+pub enum AocClientError {
+    MissingToken,
+    /// The session token was rejected, e.g. because it expired.
+    Unauthorized,
+    NotUnlocked {
+        year: u16,
+        day: u8,
+    },
+    /// There's no puzzle on the day, e.g. day 26 (or 13 from 2025 on) or a year before the first
+    /// event.
+    NoPuzzle {
+        year: u16,
+        day: u8,
+    },
+    RateLimited,
+    /// The downloaded input is an error page or a warning instead, it's not cached.
+    InvalidInput(String),
+    UnexpectedStatus(u16),
+    UnknownYear(PathBuf),
+    /// The input isn't cached and downloads are off.
+    NotCached(PathBuf),
+    InvalidConfig {
+        path: PathBuf,
+        reason: String,
+    },
+    /// The request failed before getting a response.
+    Http(Box<dyn std::error::Error + Send + Sync>),
+    Io(io::Error),
+}
+impl AocClientError {
+    /// Whether the request may succeed when retried later, e.g. right after the unlock.
+    #[must_use]
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::NotUnlocked { .. } | Self::RateLimited | Self::Http(_) => true,
+            Self::UnexpectedStatus(status) => *status >= 500,
+            _ => false,
+        }
+    }
+}
+impl fmt::Display for AocClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingToken => write!(
+                f,
+                "No session token, set SESSION_TOKEN (as env var or in the workspace .env) or \
+                session_token in ~/.config/aoc/config.toml to the `session` cookie of adventofcode.com"
+            ),
+            Self::Unauthorized => write!(f, "The session token was rejected, it may have expired"),
+            Self::NotUnlocked { year, day } => write!(f, "Day {day} of {year} isn't unlocked yet"),
+            Self::NoPuzzle { year, .. } if *year < FIRST_YEAR => {
+                write!(f, "There's no event in {year}, the first one was in {FIRST_YEAR}")
+            }
+            Self::NoPuzzle { year, day } => write!(
+                f,
+                "There's no puzzle on day {day} of {year}, the event has days 1 to {}",
+                last_day(*year)
+            ),
+            Self::RateLimited => write!(f, "Rate limited by the server, try again later"),
+            Self::InvalidInput(reason) => write!(f, "Not a puzzle input: {reason}"),
+            Self::UnexpectedStatus(status) => write!(f, "Unexpected response status {status}"),
+            Self::UnknownYear(root) => {
+                write!(f, "No year dir in {root:?}, the year has to be given")
+            }
+            Self::NotCached(path) => write!(
+                f,
+                "No cached input at {path:?} in offline mode, unset AOC_OFFLINE to download it \
+                or import it with `aoc-client cache import`"
+            ),
+            Self::InvalidConfig { path, reason } => write!(f, "Invalid config {path:?}: {reason}"),
+            Self::Http(err) => write!(f, "Request failed: {err}"),
+            Self::Io(err) => write!(f, "IO error: {err}"),
+        }
+    }
+}
+impl std::error::Error for AocClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Http(err) => Some(err.as_ref()),
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+#[cfg(feature = "async")]
+impl From<reqwest::Error> for AocClientError {
+    fn from(err: reqwest::Error) -> Self {
+        Self::Http(Box::new(err))
+    }
+}
+#[cfg(feature = "blocking")]
+impl From<ureq::Transport> for AocClientError {
+    fn from(err: ureq::Transport) -> Self {
+        Self::Http(Box::new(err))
+    }
+}
+impl From<io::Error> for AocClientError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
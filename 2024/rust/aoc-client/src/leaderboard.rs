@@ -0,0 +1,383 @@
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet},
+    fmt, fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use anyhow::{bail, ensure, Context};
+use serde::Deserialize;
+
+use crate::{client::Endpoint, Client, Config, Hms, Part, Transport};
+
+/// The server asks not to fetch a leaderboard more often than every 15 minutes.
+pub const LEADERBOARD_MAX_AGE: Duration = Duration::from_secs(15 * 60);
+
+/// When a member got a star.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct Star {
+    /// Unix timestamp.
+    pub get_star_ts: i64,
+    /// Order of the star among all the stars of the event, breaks ties of the timestamps.
+    pub star_index: u64,
+}
+
+/// The stars of a member for a day.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub struct DayStars {
+    #[serde(rename = "1")]
+    pub a: Option<Star>,
+    #[serde(rename = "2")]
+    pub b: Option<Star>,
+}
+impl DayStars {
+    #[must_use]
+    pub fn get(&self, part: Part) -> Option<&Star> {
+        match part {
+            Part::A => self.a.as_ref(),
+            Part::B => self.b.as_ref(),
+        }
+    }
+
+    /// Time from the first to the second star.
+    #[must_use]
+    pub fn delta(&self) -> Option<Duration> {
+        let secs = self.b?.get_star_ts - self.a?.get_star_ts;
+        Some(Duration::from_secs(secs.max(0) as u64))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    /// None for anonymous members.
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+    pub global_score: u64,
+    /// Unix timestamp, 0 without stars.
+    pub last_star_ts: i64,
+    #[serde(rename = "completion_day_level")]
+    pub days: BTreeMap<u8, DayStars>,
+}
+impl Member {
+    /// The name, or how the leaderboard page shows anonymous members.
+    #[must_use]
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+}
+
+/// A private leaderboard, as in its JSON API.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub owner_id: u64,
+    /// Unlock time of day 1, missing in leaderboards of older events.
+    #[serde(default)]
+    pub day1_ts: Option<i64>,
+    pub members: BTreeMap<u64, Member>,
+}
+impl Leaderboard {
+    /// Where the leaderboard is cached within the `cache_dir`.
+    #[must_use]
+    pub fn path(cache_dir: &Path, year: u16, id: u64) -> PathBuf {
+        cache_dir.join(format!("{year}/leaderboard-{id}.json"))
+    }
+
+    /// # Errors
+    /// If the JSON isn't a leaderboard, e.g. the page the server redirects to without access.
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        ensure!(
+            !json.trim_start().starts_with('<'),
+            "Got a page instead of the leaderboard, the session may not have access to it"
+        );
+        serde_json::from_str(json).context("Parse leaderboard")
+    }
+
+    /// The local score of each member: for each star, a point per member that got it later or
+    /// not at all.
+    #[must_use]
+    pub fn local_scores(&self) -> BTreeMap<u64, u64> {
+        let mut scores: BTreeMap<_, _> = self.members.keys().map(|id| (*id, 0)).collect();
+        let days: BTreeSet<_> = self
+            .members
+            .values()
+            .flat_map(|member| member.days.keys())
+            .collect();
+        for day in days {
+            for part in [Part::A, Part::B] {
+                let mut stars: Vec<_> = self
+                    .members
+                    .values()
+                    .filter_map(|member| {
+                        let star = member.days.get(day)?.get(part)?;
+                        Some((star.get_star_ts, star.star_index, member.id))
+                    })
+                    .collect();
+                stars.sort_unstable();
+                for (rank, (_, _, id)) in stars.into_iter().enumerate() {
+                    *scores.get_mut(&id).expect("Member") += (self.members.len() - rank) as u64;
+                }
+            }
+        }
+        scores
+    }
+
+    /// The members with their local scores, by score then by who got their last star first.
+    #[must_use]
+    pub fn ranking(&self) -> Vec<(&Member, u64)> {
+        let scores = self.local_scores();
+        let mut ranking: Vec<_> = self
+            .members
+            .values()
+            .map(|member| (member, scores[&member.id]))
+            .collect();
+        ranking.sort_by_key(|(member, score)| (Reverse(*score), member.last_star_ts, member.id));
+        ranking
+    }
+}
+
+impl fmt::Display for Leaderboard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ranking = self.ranking();
+        let width = ranking
+            .iter()
+            .map(|(member, _)| member.display_name().len())
+            .max()
+            .unwrap_or_default();
+        writeln!(
+            f,
+            "{} leaderboard #{}, {} members\n",
+            self.event,
+            self.owner_id,
+            self.members.len()
+        )?;
+        writeln!(f, "Rank  Score  Stars  Name")?;
+        for (rank, (member, score)) in ranking.iter().enumerate() {
+            let name = member.display_name();
+            writeln!(
+                f,
+                "{:>4}  {score:>5}  {:>5}  {name}",
+                rank + 1,
+                member.stars
+            )?;
+        }
+        let days: BTreeSet<_> = ranking
+            .iter()
+            .flat_map(|(member, _)| member.days.keys())
+            .collect();
+        for day in days {
+            writeln!(f, "\nDay {day}")?;
+            // the fastest second stars first, then the members that only got the first one
+            let mut members: Vec<_> = ranking
+                .iter()
+                .filter_map(|(member, _)| Some((*member, member.days.get(day)?)))
+                .collect();
+            members.sort_by_key(|(_, stars)| (stars.delta().is_none(), stars.delta()));
+            for (member, stars) in members {
+                let name = member.display_name();
+                match stars.delta() {
+                    Some(delta) => writeln!(f, "  {name:width$}  part 2 after {}", Hms(delta))?,
+                    None => writeln!(f, "  {name:width$}  part 1 only")?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The cached leaderboard JSON, none if it's missing or too old to reuse.
+pub(crate) fn cached_leaderboard(
+    config: &Config,
+    year: u16,
+    id: u64,
+) -> anyhow::Result<Option<String>> {
+    let path = Leaderboard::path(&config.cache_dir, year, id);
+    let modified = match fs::metadata(&path).and_then(|metadata| metadata.modified()) {
+        Ok(modified) => modified,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err).context(format!("Read {path:?}")),
+    };
+    let age = SystemTime::now()
+        .duration_since(modified)
+        .unwrap_or_default();
+    if age >= LEADERBOARD_MAX_AGE {
+        return Ok(None);
+    }
+    tracing::debug!(?path, ?age, "cached leaderboard");
+    Ok(Some(fs::read_to_string(&path)?))
+}
+
+/// Parses the downloaded leaderboard JSON and caches it.
+pub(crate) fn save_leaderboard(
+    config: &Config,
+    year: u16,
+    id: u64,
+    json: &str,
+) -> anyhow::Result<Leaderboard> {
+    let leaderboard = Leaderboard::from_json(json)?;
+    let path = Leaderboard::path(&config.cache_dir, year, id);
+    fs::create_dir_all(path.parent().expect("Year dir"))?;
+    fs::write(&path, json).with_context(|| format!("Write {path:?}"))?;
+    Ok(leaderboard)
+}
+
+/// The `id` or the [`Config::leaderboard`].
+pub(crate) fn leaderboard_id(config: &Config, id: Option<u64>) -> anyhow::Result<u64> {
+    match id.or(config.leaderboard) {
+        Some(id) => Ok(id),
+        None => bail!("No leaderboard id, give one or set AOC_LEADERBOARD"),
+    }
+}
+
+/// Downloads the private leaderboard (at most every [`LEADERBOARD_MAX_AGE`], it's cached in
+/// between), with the [`Config`] of the workspace containing the current dir.
+///
+/// The `id` (the number in the leaderboard URL) defaults to [`Config::leaderboard`].
+#[cfg(feature = "async")]
+#[allow(clippy::missing_errors_doc)]
+pub async fn fetch_leaderboard(year: Option<u16>, id: Option<u64>) -> anyhow::Result<Leaderboard> {
+    let config = Config::discover()?;
+    fetch_leaderboard_with(&config.client(), &config, year, id).await
+}
+
+/// [`fetch_leaderboard`] downloading with the `client`.
+#[allow(clippy::missing_errors_doc)]
+#[tracing::instrument(skip(client))]
+pub async fn fetch_leaderboard_with<T: Transport>(
+    client: &Client<T>,
+    config: &Config,
+    year: Option<u16>,
+    id: Option<u64>,
+) -> anyhow::Result<Leaderboard> {
+    let year = config.year(year)?;
+    let id = leaderboard_id(config, id)?;
+    if let Some(json) = cached_leaderboard(config, year, id)? {
+        return Leaderboard::from_json(&json);
+    }
+    let json = client.call(Endpoint::Leaderboard { year, id }).await?;
+    save_leaderboard(config, year, id, &json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "async")]
+    use crate::AocClient;
+    #[cfg(feature = "async")]
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    const LEADERBOARD: &str = include_str!("../fixtures/leaderboard.json");
+    const LEADERBOARD_2019: &str = include_str!("../fixtures/leaderboard_2019.json");
+
+    #[test]
+    fn parse() {
+        let leaderboard = Leaderboard::from_json(LEADERBOARD).unwrap();
+        assert_eq!("2024", leaderboard.event);
+        assert_eq!(Some(1_733_029_200), leaderboard.day1_ts);
+        assert_eq!(5, leaderboard.members.len());
+        let member = &leaderboard.members[&2345];
+        assert_eq!("Rudolph", member.display_name());
+        assert_eq!(8, member.stars);
+        let day_1 = member.days[&1];
+        assert_eq!(1_733_029_295, day_1.get(Part::A).unwrap().get_star_ts);
+        assert_eq!(Some(Duration::from_secs(35)), day_1.delta());
+        assert_eq!(None, leaderboard.members[&4567].days[&1].delta());
+        let anonymous = &leaderboard.members[&3456];
+        assert_eq!("(anonymous user #3456)", anonymous.display_name());
+    }
+
+    #[test]
+    fn older_event() {
+        let leaderboard = Leaderboard::from_json(LEADERBOARD_2019).unwrap();
+        assert_eq!(None, leaderboard.day1_ts);
+        assert!(leaderboard.members[&1234].days.is_empty());
+        assert_eq!(Some(&0), leaderboard.local_scores().get(&1234));
+    }
+
+    #[test]
+    fn not_json() {
+        let page = "<!DOCTYPE html>\n<html lang=\"en-us\">";
+        assert!(Leaderboard::from_json(page).is_err());
+    }
+
+    #[test]
+    fn local_scores() {
+        let leaderboard = Leaderboard::from_json(LEADERBOARD).unwrap();
+        let scores = leaderboard.local_scores();
+        for member in leaderboard.members.values() {
+            assert_eq!(member.local_score, scores[&member.id], "{}", member.id);
+        }
+        let ranking: Vec<_> = leaderboard
+            .ranking()
+            .iter()
+            .map(|(member, score)| (member.id, *score))
+            .collect();
+        assert_eq!(
+            vec![(2345, 39), (1234, 20), (3456, 8), (4567, 4), (5678, 0)],
+            ranking
+        );
+    }
+
+    #[test]
+    fn report() {
+        let report = Leaderboard::from_json(LEADERBOARD).unwrap().to_string();
+        let expected = [
+            "2024 leaderboard #1234, 5 members",
+            "",
+            "Rank  Score  Stars  Name",
+            "   1     39      8  Rudolph",
+            "   2     20      5  elf-ops",
+            "   3      8      3  (anonymous user #3456)",
+            "   4      4      1  Tinsel",
+            "   5      0      0  late starter",
+            "",
+            "Day 1",
+            "  Rudolph                 part 2 after 35s",
+            "  elf-ops                 part 2 after 5m 28s",
+            "  (anonymous user #3456)  part 2 after 1h 1m 40s",
+            "  Tinsel                  part 1 only",
+        ];
+        assert_eq!(
+            expected.join("\n"),
+            report.lines().take(14).collect::<Vec<_>>().join("\n")
+        );
+        assert!(report.ends_with("Day 4\n  Rudolph                 part 2 after 10s\n"));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn fetch_cached() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2024/leaderboard/private/view/1234.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(LEADERBOARD))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = AocClient::new("abc")
+            .with_base_url(server.uri())
+            .with_throttle(Duration::ZERO);
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config {
+            leaderboard: Some(1234),
+            ..Config::new(dir.path())
+        };
+        for _ in 0..2 {
+            let leaderboard = fetch_leaderboard_with(&client, &config, Some(2024), None).await;
+            assert_eq!(5, leaderboard.unwrap().members.len());
+        }
+        assert!(Leaderboard::path(&config.cache_dir, 2024, 1234).exists());
+        let no_id = Config::new(dir.path());
+        let res = fetch_leaderboard_with(&client, &no_id, Some(2024), None).await;
+        assert!(res.is_err());
+    }
+}
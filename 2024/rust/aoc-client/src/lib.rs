@@ -1,36 +1,286 @@
-use std::{env, path::PathBuf};
-use tokio::fs;
+// the request helpers are only used by the clients
+#![cfg_attr(
+    not(any(feature = "async", feature = "blocking")),
+    allow(dead_code, unused_macros)
+)]
 
-#[tracing::instrument]
-pub async fn get_input(mut root: PathBuf, day: u8) -> anyhow::Result<String> {
-    root.push(format!("target/inputs/day-{day}"));
-    if !root.exists() {
-        fs::create_dir_all(&root).await?;
-    }
-    root.push("input.txt");
-    let filename = root.as_path();
-    tracing::debug!("?filename");
-    match fs::read_to_string(&filename).await {
-        Ok(input) if !input.is_empty() => Ok(input),
-        _ => {
-            let session_token = env::var("SESSION_TOKEN")?;
-            let client = reqwest::Client::new();
-            let input = client
-                .get(format!("https://adventofcode.com/2024/day/{day}/input"))
-                .header("Cookie", format!("session={session_token}"))
-                .send()
-                .await?
-                .text()
-                .await?;
-            fs::write(filename, &input).await?;
-            Ok(input)
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+mod answers;
+mod cache;
+mod client;
+mod config;
+mod error;
+mod leaderboard;
+mod puzzle;
+mod submit;
+mod unlock;
+
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
+pub use answers::*;
+pub use cache::*;
+pub use client::*;
+pub use config::*;
+pub use error::*;
+pub use leaderboard::*;
+pub use puzzle::*;
+pub use submit::*;
+pub use unlock::*;
+
+/// The first Advent of Code event.
+pub const FIRST_YEAR: u16 = 2015;
+
+/// The last day of the event of the `year`, the events have 12 days from 2025 on.
+#[must_use]
+pub fn last_day(year: u16) -> u8 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+/// The event year from the closest ancestor dir named after one, e.g. `2024` in `aoc/2024/rust`.
+///
+/// # Errors
+/// An [`AocClientError::UnknownYear`] if no dir in the path is named after a year.
+pub fn infer_year(root: &Path) -> Result<u16, AocClientError> {
+    root.ancestors()
+        .filter_map(|dir| dir.file_name()?.to_str()?.parse().ok())
+        .find(|year| *year >= FIRST_YEAR)
+        .ok_or_else(|| AocClientError::UnknownYear(root.to_path_buf()))
+}
+
+/// Where the files of the day are cached within the `cache_dir`.
+#[must_use]
+pub fn day_dir(cache_dir: &Path, year: u16, day: u8) -> PathBuf {
+    cache_dir.join(format!("{year}/day-{day}"))
+}
+
+/// Where the input of the day is cached within the `cache_dir`.
+#[must_use]
+pub fn input_path(cache_dir: &Path, year: u16, day: u8) -> PathBuf {
+    day_dir(cache_dir, year, day).join("input.txt")
+}
+
+/// A duration like "1h 2m 3s", as the server shows wait times.
+pub(crate) struct Hms(pub Duration);
+impl fmt::Display for Hms {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let secs = self.0.as_secs();
+        let units = [
+            (secs / 86400, "d"),
+            (secs / 3600 % 24, "h"),
+            (secs / 60 % 60, "m"),
+        ];
+        for (value, unit) in units.into_iter().skip_while(|(value, _)| *value == 0) {
+            write!(f, "{value}{unit} ")?;
         }
+        write!(f, "{}s", secs % 60)
+    }
+}
+
+// the cached input, none if it has to be downloaded
+fn cached_input(config: &Config, year: u16, day: u8) -> Result<Option<String>, AocClientError> {
+    match config.cache().read(year, day)? {
+        None if config.offline => Err(AocClientError::NotCached(input_path(
+            &config.cache_dir,
+            year,
+            day,
+        ))),
+        input => Ok(input),
+    }
+}
+
+/// The cached puzzle input or downloads (and caches) it, with the [`Config`] of the workspace
+/// containing the `dir` (e.g. a day crate).
+///
+/// The `year` is inferred from the workspace path when not given. In [`Config::offline`] mode the
+/// input is only read from the cache.
+#[cfg(feature = "async")]
+#[allow(clippy::missing_errors_doc)]
+#[tracing::instrument]
+pub async fn get_input(dir: PathBuf, year: Option<u16>, day: u8) -> Result<String, AocClientError> {
+    let config = Config::discover_from(&dir)?;
+    get_input_with(&config.client(), &config, year, day).await
+}
+
+/// [`get_input`] downloading with the `client`.
+#[allow(clippy::missing_errors_doc)]
+#[tracing::instrument(skip(client))]
+pub async fn get_input_with<T: Transport>(
+    client: &Client<T>,
+    config: &Config,
+    year: Option<u16>,
+    day: u8,
+) -> Result<String, AocClientError> {
+    let year = config.year(year)?;
+    if let Some(input) = cached_input(config, year, day)? {
+        return Ok(input);
     }
+    // only validated inputs get cached
+    let input = client.call(Endpoint::Input { year, day }).await?;
+    config.cache().write(year, day, &input)?;
+    Ok(input)
 }
 
+/// The puzzle input of the workspace containing the current dir (e.g. a day crate running its
+/// benches), without an async runtime unless it has to be downloaded without the `blocking` feature.
+///
+/// # Panics
+/// If the input can't be read nor downloaded.
+#[cfg(any(feature = "async", feature = "blocking"))]
 #[tracing::instrument]
-pub fn block_on_input(day: u8) -> String {
-    let root = std::fs::canonicalize("..").expect("Parent dir");
-    let rt = tokio::runtime::Runtime::new().unwrap();
-    rt.block_on(async { get_input(root, day).await.expect("Get input") })
+pub fn block_on_input(year: Option<u16>, day: u8) -> String {
+    let input = Config::discover().and_then(|config| {
+        let year = config.year(year)?;
+        match cached_input(&config, year, day)? {
+            Some(input) => Ok(input),
+            None => download_input(&config, year, day),
+        }
+    });
+    input.unwrap_or_else(|err| panic!("Get input of day {day}: {err}"))
+}
+
+#[cfg(feature = "blocking")]
+fn download_input(config: &Config, year: u16, day: u8) -> Result<String, AocClientError> {
+    blocking::get_input_with(&config.blocking_client(), config, Some(year), day)
+}
+
+#[cfg(all(feature = "async", not(feature = "blocking")))]
+fn download_input(config: &Config, year: u16, day: u8) -> Result<String, AocClientError> {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+    rt.block_on(get_input_with(&config.client(), config, Some(year), day))
+}
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[test_case("/home/aoc/2024/rust" => Some(2024))]
+    #[test_case("/home/aoc/2015/rust/day-1" => Some(2015))]
+    #[test_case("/home/aoc/2023" => Some(2023))]
+    #[test_case("/home/1999/aoc/rust" => None)]
+    #[test_case("/home/aoc/rust" => None)]
+    fn year(root: &str) -> Option<u16> {
+        infer_year(Path::new(root)).ok()
+    }
+
+    #[test_case(0 => "0s")]
+    #[test_case(59 => "59s")]
+    #[test_case(3600 => "1h 0m 0s")]
+    #[test_case(90_061 => "1d 1h 1m 1s")]
+    fn hms(secs: u64) -> String {
+        Hms(Duration::from_secs(secs)).to_string()
+    }
+
+    #[tokio::test]
+    async fn downloads_once() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2017/day/3/input"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("368078\n"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = AocClient::new("abc")
+            .with_base_url(server.uri())
+            .with_throttle(Duration::ZERO);
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config::new(dir.path().join("2017/rust"));
+        for _ in 0..2 {
+            let input = get_input_with(&client, &config, None, 3).await.unwrap();
+            assert_eq!("368078\n", input);
+        }
+        let cached = std::fs::read_to_string(
+            dir.path()
+                .join("2017/rust/inputs/2017/day-3/input.txt"),
+        );
+        assert_eq!("368078\n", cached.unwrap());
+    }
+
+    #[tokio::test]
+    async fn explicit_year() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2025/day/12/input"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("input"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = AocClient::new("abc")
+            .with_base_url(server.uri())
+            .with_throttle(Duration::ZERO);
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config::new(dir.path().join("2024/rust"));
+        let input = get_input_with(&client, &config, Some(2025), 12).await;
+        assert_eq!("input", input.unwrap());
+        assert!(input_path(&config.cache_dir, 2025, 12).exists());
+    }
+
+    #[tokio::test]
+    async fn no_year() {
+        let client = AocClient::new("abc").with_base_url("http://127.0.0.1:9");
+        let config = Config::new("/home/aoc/rust");
+        let res = get_input_with(&client, &config, None, 1).await;
+        assert!(matches!(res, Err(AocClientError::UnknownYear(_))));
+    }
+
+    #[tokio::test]
+    async fn offline() {
+        let client = AocClient::new("abc").with_base_url("http://127.0.0.1:9");
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config {
+            offline: true,
+            ..Config::new(dir.path())
+        };
+        let res = get_input_with(&client, &config, Some(2024), 1).await;
+        assert!(matches!(res, Err(AocClientError::NotCached(_))));
+        let path = input_path(&config.cache_dir, 2024, 1);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, "1\n").unwrap();
+        let input = get_input_with(&client, &config, Some(2024), 1).await;
+        assert_eq!("1\n", input.unwrap());
+    }
+
+    #[tokio::test]
+    async fn invalid_input_not_cached() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("<!DOCTYPE html>"))
+            .mount(&server)
+            .await;
+        let client = AocClient::new("abc")
+            .with_base_url(server.uri())
+            .with_throttle(Duration::ZERO);
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config::new(dir.path());
+        let res = get_input_with(&client, &config, Some(2024), 1).await;
+        assert!(matches!(res, Err(AocClientError::InvalidInput(_))));
+        assert!(!input_path(&config.cache_dir, 2024, 1).exists());
+    }
+
+    #[tokio::test]
+    async fn unreadable_cache() {
+        let client = AocClient::new("abc").with_base_url("http://127.0.0.1:9");
+        let dir = tempfile::tempdir().unwrap();
+        // a dir in place of the cached file
+        let config = Config::new(dir.path());
+        std::fs::create_dir_all(input_path(&config.cache_dir, 2024, 1)).unwrap();
+        let res = get_input_with(&client, &config, Some(2024), 1).await;
+        assert!(matches!(res, Err(AocClientError::Io(_))));
+    }
 }
@@ -0,0 +1,119 @@
+use std::path::PathBuf;
+
+use anyhow::Context;
+use clap::{Parser, Subcommand};
+
+use aoc_client::{download_puzzle, fetch_leaderboard, AocClientError, CachedInput, Config};
+
+#[derive(Parser)]
+#[command(version, about = "Advent of Code client", long_about = None)]
+struct Cli {
+    /// Event year, inferred from the current dir when not given
+    #[arg(short, long, global = true)]
+    year: Option<u16>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Download the puzzle description and its examples into the day crate
+    Puzzle { day: u8 },
+    /// Check the session token is accepted
+    Token,
+    /// Show the rankings and star times of a private leaderboard
+    Leaderboard {
+        /// The number in the leaderboard URL, AOC_LEADERBOARD when not given
+        id: Option<u64>,
+    },
+    /// Manage the cached inputs
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum CacheCommand {
+    /// List the cached inputs, of all years unless one is given
+    List,
+    /// Print the SHA-256 checksums of the cached inputs
+    Checksums,
+    /// Remove the cached inputs of the year, or of a day
+    Purge {
+        #[arg(short, long)]
+        day: Option<u8>,
+    },
+    /// Copy the inputs in a dir (e.g. `day-1.txt` or `day-1/input.txt`) into the cache
+    Import {
+        dir: PathBuf,
+        /// Overwrite the inputs that are cached already
+        #[arg(short, long)]
+        force: bool,
+    },
+}
+
+#[tokio::main]
+#[tracing::instrument]
+async fn main() -> anyhow::Result<()> {
+    tracing_subscriber::fmt::init();
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Puzzle { day } => {
+            let puzzle = download_puzzle(cli.year, day).await?;
+            let expected = puzzle.expected();
+            for (name, answer) in [("A", expected.a), ("B", expected.b)] {
+                if let Some(answer) = answer {
+                    println!("const EXPECTED_{name}: &str = \"{answer}\";");
+                }
+            }
+        }
+        Command::Token => {
+            let config = Config::discover()?;
+            let Some((_, source)) = &config.session_token else {
+                return Err(AocClientError::MissingToken.into());
+            };
+            let user = config
+                .client()
+                .check_token()
+                .await
+                .with_context(|| format!("Session token from {source}"))?;
+            println!("The session token from {source} is valid, logged in as {user}");
+        }
+        Command::Leaderboard { id } => {
+            print!("{}", fetch_leaderboard(cli.year, id).await?);
+        }
+        Command::Cache { command } => {
+            let config = Config::discover()?;
+            let cache = config.cache();
+            match command {
+                CacheCommand::List | CacheCommand::Checksums => {
+                    let in_year = |input: &CachedInput| cli.year.is_none_or(|y| y == input.year);
+                    for input in cache.list()?.into_iter().filter(in_year) {
+                        if matches!(command, CacheCommand::Checksums) {
+                            println!("{}  {}", input.checksum()?, input.path.display());
+                        } else {
+                            println!(
+                                "{} day {:>2}  {}",
+                                input.year,
+                                input.day,
+                                input.path.display()
+                            );
+                        }
+                    }
+                }
+                CacheCommand::Purge { day } => {
+                    for path in cache.purge(config.year(cli.year)?, day)? {
+                        println!("Removed {}", path.display());
+                    }
+                }
+                CacheCommand::Import { dir, force } => {
+                    for input in cache.import(&dir, config.year(cli.year)?, force)? {
+                        println!("Imported day {} to {}", input.day, input.path.display());
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
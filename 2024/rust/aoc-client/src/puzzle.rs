@@ -0,0 +1,392 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{ensure, Context};
+
+use crate::{client::Endpoint, infer_year, Answers, Client, Config, Part, Transport};
+
+/// A part of the puzzle description.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzlePart {
+    pub markdown: String,
+    /// The `<pre><code>` blocks, the first one usually being the example input.
+    pub examples: Vec<String>,
+    /// The last emphasised code, which usually is the example's answer.
+    pub answer: Option<String>,
+}
+
+/// The unlocked parts of a puzzle page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Puzzle {
+    pub parts: Vec<PuzzlePart>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token<'a> {
+    Open(&'a str, &'a str),
+    Close(&'a str),
+    Text(&'a str),
+}
+
+// tags and the text between them, tag names are lowercased by the page already
+fn tokens(html: &str) -> impl Iterator<Item = Token<'_>> {
+    let mut rest = html;
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let token = match rest.strip_prefix('<') {
+            Some(tag) => {
+                let end = tag.find('>').unwrap_or(tag.len());
+                let (tag, after) = (&tag[..end], tag.get(end + 1..).unwrap_or_default());
+                rest = after;
+                match tag.strip_prefix('/') {
+                    Some(name) => Token::Close(name.trim()),
+                    None => {
+                        let (name, attrs) = tag.split_once(' ').unwrap_or((tag, ""));
+                        Token::Open(name, attrs)
+                    }
+                }
+            }
+            None => {
+                let end = rest.find('<').unwrap_or(rest.len());
+                let text = &rest[..end];
+                rest = &rest[end..];
+                Token::Text(text)
+            }
+        };
+        Some(token)
+    })
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+fn attribute<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let (_, value) = attrs.split_once(&format!("{name}=\""))?;
+    Some(value.split_once('"')?.0)
+}
+
+impl PuzzlePart {
+    fn from_article(article: &str) -> Self {
+        let mut markdown = String::new();
+        let mut examples = Vec::new();
+        let mut answer = None;
+        let (mut in_pre, mut in_code, mut in_em) = (false, false, false);
+        let mut links = Vec::new();
+        let mut emphasised_code = String::new();
+        for token in tokens(article) {
+            match token {
+                Token::Open("pre", _) => {
+                    in_pre = true;
+                    examples.push(String::new());
+                    markdown.push_str("```\n");
+                }
+                Token::Close("pre") => {
+                    in_pre = false;
+                    if !markdown.ends_with('\n') {
+                        markdown.push('\n');
+                    }
+                    markdown.push_str("```\n\n");
+                }
+                Token::Text(text) if in_pre => {
+                    let text = decode_entities(text);
+                    markdown.push_str(&text);
+                    examples.last_mut().expect("Open pre").push_str(&text);
+                }
+                _ if in_pre => (),
+                Token::Open("code", _) => {
+                    in_code = true;
+                    emphasised_code.clear();
+                    markdown.push('`');
+                }
+                Token::Close("code") => {
+                    in_code = false;
+                    if !emphasised_code.is_empty() {
+                        answer = Some(std::mem::take(&mut emphasised_code));
+                    }
+                    markdown.push('`');
+                }
+                Token::Open("em", _) => {
+                    in_em = true;
+                    if !in_code {
+                        markdown.push_str("**");
+                    }
+                }
+                Token::Close("em") => {
+                    in_em = false;
+                    if !in_code {
+                        markdown.push_str("**");
+                    }
+                }
+                Token::Open("h2", _) => markdown.push_str("## "),
+                Token::Close("h2" | "p" | "ul") => markdown.push_str("\n\n"),
+                Token::Open("li", _) => markdown.push_str("- "),
+                Token::Close("li") => markdown.push('\n'),
+                Token::Open("a", attrs) => {
+                    links.push(attribute(attrs, "href").unwrap_or_default());
+                    markdown.push('[');
+                }
+                Token::Close("a") => {
+                    let href = links.pop().unwrap_or_default();
+                    markdown.push_str(&format!("]({href})"));
+                }
+                // whitespace between the blocks
+                Token::Text(text)
+                    if text.trim().is_empty()
+                        && (markdown.is_empty() || markdown.ends_with('\n')) => {}
+                Token::Text(text) => {
+                    let text = decode_entities(text).replace('\n', " ");
+                    if in_code && in_em {
+                        emphasised_code.push_str(&text);
+                    }
+                    markdown.push_str(&text);
+                }
+                Token::Open(..) | Token::Close(_) => (),
+            }
+        }
+        // a list ends with the newline of its last item too
+        let markdown = markdown.replace("\n\n\n", "\n\n").trim().to_string();
+        Self {
+            markdown,
+            examples,
+            answer,
+        }
+    }
+}
+
+impl Puzzle {
+    /// Parses the `<article>`s of the puzzle page.
+    ///
+    /// # Errors
+    /// If the page has no puzzle description.
+    pub fn from_html(html: &str) -> anyhow::Result<Self> {
+        let parts: Vec<_> = html
+            .split("<article")
+            .skip(1)
+            .filter_map(|article| {
+                let (_, article) = article.split_once('>')?;
+                Some(article.split_once("</article>")?.0)
+            })
+            .map(PuzzlePart::from_article)
+            .collect();
+        ensure!(!parts.is_empty(), "No puzzle description in the page");
+        Ok(Self { parts })
+    }
+
+    #[must_use]
+    pub fn part(&self, part: Part) -> Option<&PuzzlePart> {
+        self.parts.get(part.level() as usize - 1)
+    }
+
+    #[must_use]
+    pub fn markdown(&self) -> String {
+        let parts: Vec<_> = self
+            .parts
+            .iter()
+            .map(|part| part.markdown.as_str())
+            .collect();
+        parts.join("\n\n") + "\n"
+    }
+
+    /// The example input files, part b only gets its own file if its first block is new.
+    #[must_use]
+    pub fn example_files(&self) -> Vec<(&'static str, &str)> {
+        let mut files = Vec::new();
+        let first_example = |part| {
+            self.part(part)
+                .and_then(|part| part.examples.first())
+                .map(String::as_str)
+        };
+        if let Some(example) = first_example(Part::A) {
+            files.push(("example.txt", example));
+        }
+        if let Some(example) = first_example(Part::B) {
+            let repeated = self.parts[0].examples.iter().any(|ex| ex == example);
+            if !repeated {
+                files.push(("example_b.txt", example));
+            }
+        }
+        files
+    }
+
+    /// The (heuristic) answers of the examples.
+    #[must_use]
+    pub fn expected(&self) -> Answers {
+        let answer = |part| self.part(part).and_then(|part| part.answer.clone());
+        Answers {
+            a: answer(Part::A),
+            b: answer(Part::B),
+        }
+    }
+
+    /// Writes `puzzle.md`, `inputs/expected.toml` and the example inputs (unless they exist
+    /// already, they may have been fixed by hand) into the day crate dir, returns the written files.
+    ///
+    /// # Errors
+    /// If the files can't be written.
+    pub fn save(&self, crate_dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+        let inputs = crate_dir.join("inputs");
+        fs::create_dir_all(&inputs).with_context(|| format!("Create {inputs:?}"))?;
+        let mut written = Vec::new();
+        let markdown = crate_dir.join("puzzle.md");
+        fs::write(&markdown, self.markdown())?;
+        written.push(markdown);
+        for (name, example) in self.example_files() {
+            let path = inputs.join(name);
+            if !path.exists() {
+                fs::write(&path, example)?;
+                written.push(path);
+            }
+        }
+        let expected = inputs.join("expected.toml");
+        self.expected().save(&expected)?;
+        written.push(expected);
+        Ok(written)
+    }
+}
+
+/// Parses the downloaded puzzle page and saves it into the day crate.
+pub(crate) fn save_page(config: &Config, day: u8, html: &str) -> anyhow::Result<Puzzle> {
+    let puzzle = Puzzle::from_html(html)?;
+    let written = puzzle.save(&config.crate_dir(day))?;
+    tracing::info!(?written, "puzzle saved");
+    Ok(puzzle)
+}
+
+/// Downloads the puzzle page and saves it into the day crate, with the [`Config`] of the
+/// workspace containing the current dir.
+#[cfg(feature = "async")]
+#[allow(clippy::missing_errors_doc)]
+pub async fn download_puzzle(year: Option<u16>, day: u8) -> anyhow::Result<Puzzle> {
+    let config = Config::discover()?;
+    download_puzzle_with(&config.client(), &config, year, day).await
+}
+
+/// [`download_puzzle`] fetching with the `client`.
+///
+/// # Errors
+/// If the `year` isn't the one of the workspace, its day crates would be overwritten.
+#[tracing::instrument(skip(client))]
+pub async fn download_puzzle_with<T: Transport>(
+    client: &Client<T>,
+    config: &Config,
+    year: Option<u16>,
+    day: u8,
+) -> anyhow::Result<Puzzle> {
+    let year = config.year(year)?;
+    if let Ok(workspace_year) = infer_year(&config.root) {
+        ensure!(
+            year == workspace_year,
+            "The puzzle of {year} doesn't belong in the {workspace_year} workspace {:?}",
+            config.root
+        );
+    }
+    let html = client.call(Endpoint::Puzzle { year, day }).await?;
+    save_page(config, day, &html)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "async")]
+    use crate::AocClient;
+    #[cfg(feature = "async")]
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    const PART_1: &str = include_str!("../fixtures/puzzle_part_1.html");
+    const PART_2: &str = include_str!("../fixtures/puzzle_part_2.html");
+    const EXAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    #[test]
+    fn part_1() {
+        let puzzle = Puzzle::from_html(PART_1).unwrap();
+        assert_eq!(1, puzzle.parts.len());
+        let part = &puzzle.parts[0];
+        assert_eq!(vec![EXAMPLE.to_string()], part.examples);
+        assert_eq!(Some("11".to_string()), part.answer);
+        assert_eq!(vec![("example.txt", EXAMPLE)], puzzle.example_files());
+        assert!(puzzle.expected().b.is_none());
+    }
+
+    #[test]
+    fn part_2() {
+        let puzzle = Puzzle::from_html(PART_2).unwrap();
+        assert_eq!(2, puzzle.parts.len());
+        let expected = puzzle.expected();
+        assert_eq!(Some("11"), expected.get(Part::A));
+        assert_eq!(Some("31"), expected.get(Part::B));
+        // part 2 reuses the example
+        assert_eq!(1, puzzle.example_files().len());
+    }
+
+    #[test]
+    fn markdown() {
+        let markdown = Puzzle::from_html(PART_1).unwrap().markdown();
+        assert!(markdown.starts_with("## --- Day 1: Historian Hysteria ---\n\n"));
+        assert!(markdown.contains("the **Chief Historian** is missing"));
+        assert!(markdown.contains("[Advent of Code](/2024/about)"));
+        assert!(markdown.contains("```\n3   4\n4   3\n"));
+        assert!(markdown.contains("- The smallest number in the left list is `1`"));
+        assert!(markdown.contains("a total distance of `11`!"));
+        assert!(markdown.contains("& it's `<= 9` each"));
+        assert!(!markdown.contains("<em>") && !markdown.contains("<code>"));
+    }
+
+    #[test]
+    fn no_description() {
+        assert!(Puzzle::from_html("<html><body>Not found</body></html>").is_err());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn download() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2024/day/1"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(PART_2))
+            .mount(&server)
+            .await;
+        let client = AocClient::new("abc")
+            .with_base_url(server.uri())
+            .with_throttle(std::time::Duration::ZERO);
+        let dir = tempfile::tempdir().unwrap();
+        let crate_dir = dir.path().join("day-1");
+        fs::create_dir_all(crate_dir.join("inputs")).unwrap();
+        fs::write(crate_dir.join("inputs/example.txt"), "fixed").unwrap();
+        download_puzzle_with(&client, &Config::new(dir.path()), Some(2024), 1)
+            .await
+            .unwrap();
+        assert!(fs::read_to_string(crate_dir.join("puzzle.md"))
+            .unwrap()
+            .contains("## --- Part Two ---"));
+        let expected = Answers::load(&crate_dir.join("inputs/expected.toml")).unwrap();
+        assert_eq!(Some("31"), expected.get(Part::B));
+        // existing examples are kept
+        let example = fs::read_to_string(crate_dir.join("inputs/example.txt"));
+        assert_eq!("fixed", example.unwrap());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn other_year() {
+        let client = AocClient::new("abc").with_base_url("http://127.0.0.1:9");
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config::new(dir.path().join("2024/rust"));
+        let res = download_puzzle_with(&client, &config, Some(2015), 1).await;
+        assert!(res.unwrap_err().to_string().contains("2024 workspace"));
+        assert!(!config.crate_dir(1).exists());
+    }
+}
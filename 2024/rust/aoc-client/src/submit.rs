@@ -0,0 +1,467 @@
+use std::{
+    cmp::Ordering,
+    fmt, fs,
+    io::Write,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+use anyhow::{bail, Context};
+
+use crate::{client::Endpoint, day_dir, Answers, Client, Config, Transport};
+
+/// Puzzle part, the answer endpoint calls it the level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    A,
+    B,
+}
+impl Part {
+    #[must_use]
+    pub fn level(self) -> u8 {
+        match self {
+            Part::A => 1,
+            Part::B => 2,
+        }
+    }
+}
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::A => write!(f, "a"),
+            Part::B => write!(f, "b"),
+        }
+    }
+}
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" | "1" => Ok(Part::A),
+            "b" | "2" => Ok(Part::B),
+            _ => bail!("Invalid puzzle part '{s}'"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmissionResult {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited { wait: Duration },
+    AlreadySolved,
+}
+impl SubmissionResult {
+    /// Parses the answer response page.
+    ///
+    /// # Errors
+    /// If the page doesn't say how the answer went.
+    pub fn from_page(page: &str) -> anyhow::Result<Self> {
+        let article = page
+            .split_once("<article>")
+            .and_then(|(_, article)| article.split_once("</article>"))
+            .map_or(page, |(article, _)| article);
+        let text = strip_tags(article);
+        let res = if text.contains("That's the right answer") {
+            Self::Correct
+        } else if text.contains("You don't seem to be solving the right level") {
+            Self::AlreadySolved
+        } else if text.contains("You gave an answer too recently") {
+            let wait = text
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .and_then(|(wait, _)| parse_wait(wait))
+                .unwrap_or_default();
+            Self::RateLimited { wait }
+        } else if text.contains("That's not the right answer") {
+            if text.contains("your answer is too high") {
+                Self::TooHigh
+            } else if text.contains("your answer is too low") {
+                Self::TooLow
+            } else {
+                Self::Wrong
+            }
+        } else {
+            bail!("Unexpected answer response: {}", text.trim());
+        };
+        Ok(res)
+    }
+
+    // name in the submissions file, only the verdicts on the answer itself are recorded
+    fn record_name(self) -> Option<&'static str> {
+        match self {
+            Self::Correct => Some("correct"),
+            Self::TooHigh => Some("too-high"),
+            Self::TooLow => Some("too-low"),
+            Self::Wrong => Some("wrong"),
+            Self::RateLimited { .. } | Self::AlreadySolved => None,
+        }
+    }
+
+    fn from_record_name(name: &str) -> Option<Self> {
+        [Self::Correct, Self::TooHigh, Self::TooLow, Self::Wrong]
+            .into_iter()
+            .find(|res| res.record_name() == Some(name))
+    }
+}
+impl fmt::Display for SubmissionResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "Correct"),
+            Self::TooHigh => write!(f, "Wrong, too high"),
+            Self::TooLow => write!(f, "Wrong, too low"),
+            Self::Wrong => write!(f, "Wrong"),
+            Self::RateLimited { wait } => write!(f, "Rate limited, wait {}s", wait.as_secs()),
+            Self::AlreadySolved => write!(f, "Already solved"),
+        }
+    }
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text
+}
+
+// e.g. "1m 23s" or "45s"
+fn parse_wait(wait: &str) -> Option<Duration> {
+    wait.split_whitespace()
+        .try_fold(Duration::ZERO, |acc, part| {
+            let (value, unit) = part.split_at(part.len().checked_sub(1)?);
+            let secs = match unit {
+                "h" => 3600,
+                "m" => 60,
+                "s" => 1,
+                _ => return None,
+            };
+            Some(acc + Duration::from_secs(value.parse::<u64>().ok()? * secs))
+        })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Submission {
+    part: Part,
+    result: SubmissionResult,
+    answer: String,
+}
+
+/// Log of the submitted answers of a day, with a `part<TAB>result<TAB>answer` line per answer.
+#[derive(Debug, Clone)]
+pub struct Submissions {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+impl Submissions {
+    /// Where the submissions of the day are recorded within the `cache_dir`.
+    #[must_use]
+    pub fn path(cache_dir: &Path, year: u16, day: u8) -> PathBuf {
+        day_dir(cache_dir, year, day).join("submissions.txt")
+    }
+
+    /// Reads the recorded submissions, none if the file doesn't exist yet.
+    ///
+    /// # Errors
+    /// If the file can't be read or has invalid lines.
+    pub fn load(path: PathBuf) -> anyhow::Result<Self> {
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err).context(format!("Read {path:?}")),
+        };
+        let submissions = content
+            .lines()
+            .map(|line| {
+                let mut fields = line.splitn(3, '\t');
+                let (Some(part), Some(result), Some(answer)) =
+                    (fields.next(), fields.next(), fields.next())
+                else {
+                    bail!("Invalid submission '{line}' in {path:?}");
+                };
+                Ok(Submission {
+                    part: part.parse()?,
+                    result: SubmissionResult::from_record_name(result)
+                        .with_context(|| format!("Invalid submission result '{result}'"))?,
+                    answer: answer.to_string(),
+                })
+            })
+            .collect::<anyhow::Result<_>>()?;
+        Ok(Self { path, submissions })
+    }
+
+    /// The result of the answer as far as it follows from the recorded ones, e.g. a numeric
+    /// answer above one that was too high.
+    #[must_use]
+    pub fn known_result(&self, part: Part, answer: &str) -> Option<SubmissionResult> {
+        let cmp = |recorded: &str| match (answer.parse::<i128>(), recorded.parse::<i128>()) {
+            (Ok(answer), Ok(recorded)) => Some(answer.cmp(&recorded)),
+            _ => None,
+        };
+        self.submissions
+            .iter()
+            .filter(|submission| submission.part == part)
+            .find_map(|submission| {
+                use SubmissionResult::{Correct, TooHigh, TooLow, Wrong};
+                if submission.answer == answer {
+                    return Some(submission.result);
+                }
+                match (submission.result, cmp(&submission.answer)) {
+                    (Correct | TooHigh, Some(Ordering::Greater)) => Some(TooHigh),
+                    (Correct | TooLow, Some(Ordering::Less)) => Some(TooLow),
+                    (Correct, _) => Some(Wrong),
+                    _ => None,
+                }
+            })
+    }
+
+    /// Appends the submission if its result is about the answer itself.
+    ///
+    /// # Errors
+    /// If the file can't be written.
+    pub fn record(
+        &mut self,
+        part: Part,
+        result: SubmissionResult,
+        answer: &str,
+    ) -> anyhow::Result<()> {
+        let Some(name) = result.record_name() else {
+            return Ok(());
+        };
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{part}\t{name}\t{answer}")?;
+        self.submissions.push(Submission {
+            part,
+            result,
+            answer: answer.to_string(),
+        });
+        Ok(())
+    }
+}
+
+/// The recorded result of the answer, none if it has to be submitted.
+pub(crate) fn recorded_result(
+    config: &Config,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> anyhow::Result<Option<SubmissionResult>> {
+    if answer.is_empty() || answer.contains(['\t', '\n']) {
+        bail!("Invalid answer '{answer}'");
+    }
+    let submissions = Submissions::load(Submissions::path(&config.cache_dir, year, day))?;
+    let result = submissions.known_result(part, answer);
+    if let Some(result) = result {
+        tracing::info!(%result, "known answer, not submitted");
+    }
+    Ok(result)
+}
+
+/// Records the result of the submitted answer, and the answer itself if it's correct.
+pub(crate) fn record_result(
+    config: &Config,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+    page: &str,
+) -> anyhow::Result<SubmissionResult> {
+    let result = SubmissionResult::from_page(page)?;
+    let mut submissions = Submissions::load(Submissions::path(&config.cache_dir, year, day))?;
+    submissions.record(part, result, answer)?;
+    if result == SubmissionResult::Correct {
+        let path = Answers::path(&config.cache_dir, year, day);
+        let mut answers = Answers::load(&path)?;
+        answers.set(part, answer);
+        answers.save(&path)?;
+    }
+    Ok(result)
+}
+
+/// Submits the answer with the [`Config`] of the workspace containing the current dir.
+///
+/// Known wrong answers (and answers a recorded result rules out) aren't submitted again.
+#[cfg(feature = "async")]
+#[allow(clippy::missing_errors_doc)]
+pub async fn submit_answer(
+    year: Option<u16>,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> anyhow::Result<SubmissionResult> {
+    let config = Config::discover()?;
+    submit_answer_with(&config.client(), &config, year, day, part, answer).await
+}
+
+/// [`submit_answer`] posting with the `client` and recording within the cache dir of the `config`.
+#[allow(clippy::missing_errors_doc)]
+#[tracing::instrument(skip(client))]
+pub async fn submit_answer_with<T: Transport>(
+    client: &Client<T>,
+    config: &Config,
+    year: Option<u16>,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> anyhow::Result<SubmissionResult> {
+    let year = config.year(year)?;
+    let answer = answer.trim();
+    if let Some(result) = recorded_result(config, year, day, part, answer)? {
+        return Ok(result);
+    }
+    let endpoint = Endpoint::Answer {
+        year,
+        day,
+        part,
+        answer,
+    };
+    let page = client.call(endpoint).await?;
+    record_result(config, year, day, part, answer, &page)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "async")]
+    use crate::AocClient;
+    use test_case::test_case;
+    #[cfg(feature = "async")]
+    use wiremock::{
+        matchers::{body_string, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    fn page(article: &str) -> String {
+        format!("<html><body><main><article><p>{article}</p></article></main></body></html>")
+    }
+
+    #[test_case("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer." => SubmissionResult::Correct)]
+    #[test_case("That's not the right answer; your answer is too high.  If you're stuck, ..." => SubmissionResult::TooHigh)]
+    #[test_case("That's not the right answer; your answer is too low.  Please wait one minute." => SubmissionResult::TooLow)]
+    #[test_case("That's not the right answer.  If you're stuck, make sure you're using the full input data" => SubmissionResult::Wrong)]
+    #[test_case("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait." => SubmissionResult::RateLimited { wait: Duration::from_secs(65) })]
+    #[test_case("You gave an answer too recently.  You have 38s left to wait." => SubmissionResult::RateLimited { wait: Duration::from_secs(38) })]
+    #[test_case("You don't seem to be solving the right level.  Did you already complete it?" => SubmissionResult::AlreadySolved)]
+    fn from_page(article: &str) -> SubmissionResult {
+        SubmissionResult::from_page(&page(article)).unwrap()
+    }
+
+    #[test]
+    fn unexpected_page() {
+        assert!(SubmissionResult::from_page(&page("Puzzle inputs differ by user.")).is_err());
+    }
+
+    #[cfg(feature = "async")]
+    async fn stub(article: &str, expected_requests: u64) -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/2024/day/7/answer"))
+            .and(body_string("level=1&answer=3749"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(page(article)))
+            .expect(expected_requests)
+            .mount(&server)
+            .await;
+        server
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn known_wrong_not_resubmitted() {
+        let server = stub("That's not the right answer; your answer is too low.", 1).await;
+        let client = AocClient::new("abc")
+            .with_base_url(server.uri())
+            .with_throttle(Duration::ZERO);
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config::new(dir.path());
+        for answer in ["3749", " 3749\n", "100"] {
+            let res = submit_answer_with(&client, &config, Some(2024), 7, Part::A, answer);
+            assert_eq!(SubmissionResult::TooLow, res.await.unwrap());
+        }
+        let recorded = std::fs::read_to_string(Submissions::path(&config.cache_dir, 2024, 7));
+        assert_eq!("a\ttoo-low\t3749\n", recorded.unwrap());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn correct_answer_kept() {
+        let server = stub("That's the right answer!  You are one gold star closer.", 1).await;
+        let client = AocClient::new("abc")
+            .with_base_url(server.uri())
+            .with_throttle(Duration::ZERO);
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config::new(dir.path());
+        let res = submit_answer_with(&client, &config, Some(2024), 7, Part::A, "3749");
+        assert_eq!(SubmissionResult::Correct, res.await.unwrap());
+        let answers = Answers::load(&Answers::path(&config.cache_dir, 2024, 7)).unwrap();
+        assert_eq!(Some("3749"), answers.get(Part::A));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn rate_limit_not_recorded() {
+        let server = stub(
+            "You gave an answer too recently.  You have 12s left to wait.",
+            2,
+        )
+        .await;
+        let client = AocClient::new("abc")
+            .with_base_url(server.uri())
+            .with_throttle(Duration::ZERO);
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config::new(dir.path());
+        for _ in 0..2 {
+            let res = submit_answer_with(&client, &config, Some(2024), 7, Part::A, "3749");
+            let wait = Duration::from_secs(12);
+            assert_eq!(SubmissionResult::RateLimited { wait }, res.await.unwrap());
+        }
+        assert!(!Submissions::path(&config.cache_dir, 2024, 7).exists());
+    }
+
+    #[test]
+    fn known_results() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("submissions.txt");
+        let mut submissions = Submissions::load(path.clone()).unwrap();
+        submissions
+            .record(Part::A, SubmissionResult::TooHigh, "500")
+            .unwrap();
+        submissions
+            .record(Part::B, SubmissionResult::Correct, "42")
+            .unwrap();
+        let submissions = Submissions::load(path).unwrap();
+        assert_eq!(
+            Some(SubmissionResult::TooHigh),
+            submissions.known_result(Part::A, "501")
+        );
+        assert_eq!(None, submissions.known_result(Part::A, "499"));
+        assert_eq!(
+            Some(SubmissionResult::Correct),
+            submissions.known_result(Part::B, "42")
+        );
+        assert_eq!(
+            Some(SubmissionResult::TooLow),
+            submissions.known_result(Part::B, "41")
+        );
+        assert_eq!(
+            Some(SubmissionResult::Wrong),
+            submissions.known_result(Part::B, "abc")
+        );
+    }
+}
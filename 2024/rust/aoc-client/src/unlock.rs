@@ -0,0 +1,234 @@
+#[cfg(feature = "async")]
+use std::path::PathBuf;
+use std::{
+    fmt,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{get_input_with, last_day, AocClientError, Client, Config, Hms, Transport, FIRST_YEAR};
+
+/// The puzzles unlock at midnight EST (UTC-5).
+pub const UNLOCK_HOUR_UTC: u64 = 5;
+
+/// When the puzzle of a day unlocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unlock {
+    year: u16,
+    day: u8,
+}
+impl Unlock {
+    /// # Errors
+    /// An [`AocClientError::NoPuzzle`] for a year before [`FIRST_YEAR`] or a day after the
+    /// [`last_day`] of the event.
+    pub fn new(year: u16, day: u8) -> Result<Self, AocClientError> {
+        if year < FIRST_YEAR || !(1..=last_day(year)).contains(&day) {
+            return Err(AocClientError::NoPuzzle { year, day });
+        }
+        Ok(Self { year, day })
+    }
+
+    #[must_use]
+    pub fn year(self) -> u16 {
+        self.year
+    }
+
+    #[must_use]
+    pub fn day(self) -> u8 {
+        self.day
+    }
+
+    /// Seconds since the unix epoch.
+    #[must_use]
+    pub fn timestamp(self) -> u64 {
+        days_since_epoch(self.year, 12, self.day) * 86400 + UNLOCK_HOUR_UTC * 3600
+    }
+
+    #[must_use]
+    pub fn time(self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.timestamp())
+    }
+
+    /// The time left until the unlock at `now`, none once it's unlocked.
+    #[must_use]
+    pub fn remaining(self, now: SystemTime) -> Option<Duration> {
+        self.time()
+            .duration_since(now)
+            .ok()
+            .filter(|remaining| !remaining.is_zero())
+    }
+}
+impl fmt::Display for Unlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-12-{:02} {UNLOCK_HOUR_UTC:02}:00:00 UTC",
+            self.year, self.day
+        )
+    }
+}
+
+// days from 1970-01-01 to the date (of the proleptic Gregorian calendar, from 1970 on)
+fn days_since_epoch(year: u16, month: u8, day: u8) -> u64 {
+    let (year, month, day) = (u64::from(year), u64::from(month), u64::from(day));
+    // years starting in March, so the leap day is the last day of its year
+    let year = if month <= 2 { year - 1 } else { year };
+    let (era, year_of_era) = (year / 400, year % 400);
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Delays between the retries of a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Backoff {
+    pub initial: Duration,
+    pub max: Duration,
+    pub retries: u32,
+}
+impl Default for Backoff {
+    fn default() -> Self {
+        Self {
+            initial: Duration::from_secs(1),
+            max: Duration::from_secs(60),
+            retries: 8,
+        }
+    }
+}
+impl Backoff {
+    /// The delay before the retry `attempt` (from 0), doubling each time up to the max.
+    #[must_use]
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = 2u32.checked_pow(attempt).unwrap_or(u32::MAX);
+        self.initial.saturating_mul(factor).min(self.max)
+    }
+}
+
+/// [`wait_for_input_with`] with the [`Config`] of the workspace containing the `dir`.
+#[cfg(feature = "async")]
+#[allow(clippy::missing_errors_doc)]
+#[tracing::instrument]
+pub async fn wait_for_input(
+    dir: PathBuf,
+    year: Option<u16>,
+    day: u8,
+    backoff: Backoff,
+) -> Result<String, AocClientError> {
+    let config = Config::discover_from(&dir)?;
+    wait_for_input_with(&config.client(), &config, year, day, backoff).await
+}
+
+/// Waits until the puzzle unlocks, then gets the input like [`crate::get_input_with`], retrying
+/// transient errors (e.g. a server not quite unlocked yet) with the `backoff`.
+#[allow(clippy::missing_errors_doc)]
+#[tracing::instrument(skip(client))]
+pub async fn wait_for_input_with<T: Transport>(
+    client: &Client<T>,
+    config: &Config,
+    year: Option<u16>,
+    day: u8,
+    backoff: Backoff,
+) -> Result<String, AocClientError> {
+    let year = config.year(year)?;
+    let unlock = Unlock::new(year, day)?;
+    if let Some(remaining) = unlock.remaining(SystemTime::now()) {
+        tracing::info!(%unlock, remaining = %Hms(remaining), "waiting for the unlock");
+        T::sleep(remaining).await;
+    }
+    let mut attempt = 0;
+    loop {
+        match get_input_with(client, config, Some(year), day).await {
+            Err(err) if err.is_transient() && attempt < backoff.retries => {
+                let delay = backoff.delay(attempt);
+                tracing::warn!(%err, ?delay, "retrying");
+                T::sleep(delay).await;
+                attempt += 1;
+            }
+            input => return input,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "async")]
+    use crate::AocClient;
+    use test_case::test_case;
+    #[cfg(feature = "async")]
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[test_case(2015, 1 => 1_448_946_000)]
+    #[test_case(2023, 7 => 1_701_925_200)]
+    #[test_case(2024, 1 => 1_733_029_200)]
+    #[test_case(2024, 25 => 1_735_102_800)]
+    #[test_case(2025, 12 => 1_765_515_600)]
+    fn timestamp(year: u16, day: u8) -> u64 {
+        Unlock::new(year, day).unwrap().timestamp()
+    }
+
+    #[test_case(2014, 1)]
+    #[test_case(1969, 1)]
+    #[test_case(2024, 0)]
+    #[test_case(2024, 26)]
+    #[test_case(2025, 13)]
+    #[test_case(2025, 20)]
+    fn no_puzzle(year: u16, day: u8) {
+        let res = Unlock::new(year, day);
+        assert!(matches!(res, Err(AocClientError::NoPuzzle { .. })));
+    }
+
+    #[test]
+    fn remaining() {
+        let unlock = Unlock::new(2024, 7).unwrap();
+        assert_eq!("2024-12-07 05:00:00 UTC", unlock.to_string());
+        let before = unlock.time() - Duration::from_secs(90);
+        assert_eq!(Some(Duration::from_secs(90)), unlock.remaining(before));
+        assert_eq!(None, unlock.remaining(unlock.time()));
+        assert_eq!(None, unlock.remaining(SystemTime::now()));
+    }
+
+    #[test_case(0 => Duration::from_secs(1))]
+    #[test_case(3 => Duration::from_secs(8))]
+    #[test_case(10 => Duration::from_secs(60))]
+    #[test_case(40 => Duration::from_secs(60))]
+    fn delay(attempt: u32) -> Duration {
+        Backoff::default().delay(attempt)
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn retries() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/2024/day/7/input"))
+            .respond_with(ResponseTemplate::new(404).set_body_string("... before it unlocks!"))
+            .up_to_n_times(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/2024/day/7/input"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("190: 10 19\n"))
+            .mount(&server)
+            .await;
+        let client = AocClient::new("abc")
+            .with_base_url(server.uri())
+            .with_throttle(Duration::ZERO);
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config::new(dir.path());
+        let backoff = Backoff {
+            initial: Duration::from_millis(10),
+            ..Backoff::default()
+        };
+        let input = wait_for_input_with(&client, &config, Some(2024), 7, backoff).await;
+        assert_eq!("190: 10 19\n", input.unwrap());
+        let no_retries = Backoff {
+            retries: 0,
+            ..backoff
+        };
+        let res = wait_for_input_with(&client, &config, Some(2024), 8, no_retries).await;
+        assert!(matches!(res, Err(AocClientError::UnexpectedStatus(404))));
+    }
+}
@@ -81,6 +81,20 @@ fn main() {
     let input = &inputs[&{num}];
     {name}::solution::part_a(&input).expect("Valid result for {name} part a");
     {name}::solution::part_b(&input).expect("Valid result for {name} part b");
+"#,
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let code_outputs = days
+        .iter()
+        .map(|d| {
+            let num = d.num;
+            let name = &d.code_name;
+            format!(
+                r#"
+    let input = &inputs[&{num}];
+    outputs.push(({num}, [{name}::solution::part_a(&input), {name}::solution::part_b(&input)]));
 "#,
             )
         })
@@ -93,7 +107,8 @@ fn main() {
             let name = &d.code_name;
             format!(
                 r#"
-    let input = aoc_client::get_input(root.clone(), {num}).await.expect("Get input for {name}");
+    let input = aoc_client::blocking::get_input_with(&client, &config, None, {num})
+        .unwrap_or_else(|err| panic!("Get input for {name}: {{err}}"));
     inputs.insert({num}, input);
 "#,
             )
@@ -107,14 +122,18 @@ pub fn run(inputs: &HashMap<usize, String>) {{
 {code_run}
 }}
 
+pub fn outputs(inputs: &HashMap<usize, String>) -> Vec<(u8, [anyhow::Result<String>; 2])> {{
+    let mut outputs = Vec::new();
+{code_outputs}
+    outputs
+}}
+
 pub fn inputs() -> HashMap<usize, String> {{
-    let root = std::fs::canonicalize("..").expect("Parent dir");
-    let rt = tokio::runtime::Runtime::new().unwrap();
-    rt.block_on(async {{
-        let mut inputs = HashMap::new();
-        {code_get_input}
-        inputs
-     }})
+    let config = aoc_client::Config::discover().expect("Workspace config");
+    let client = config.blocking_client();
+    let mut inputs = HashMap::new();
+    {code_get_input}
+    inputs
 }}
 "#
     );
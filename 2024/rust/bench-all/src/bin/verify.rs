@@ -0,0 +1,11 @@
+use bench_all::{get_inputs, verify_all};
+
+fn main() -> anyhow::Result<()> {
+    tracing_subscriber::fmt::init();
+    let failures = verify_all(&get_inputs())?;
+    anyhow::ensure!(
+        failures == 0,
+        "{failures} outputs don't match the known answers"
+    );
+    Ok(())
+}
@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use aoc_client::{Answers, Config, Part, Verification};
+
 include!(concat!(env!("OUT_DIR"), "/runner.rs"));
 
 pub fn run_all(inputs: &HashMap<usize, String>) {
@@ -9,3 +11,33 @@ pub fn run_all(inputs: &HashMap<usize, String>) {
 pub fn get_inputs() -> HashMap<usize, String> {
     inputs()
 }
+
+/// Runs all the days on their inputs and prints how the outputs compare to the known answers,
+/// returns the number of mismatched (or failed) parts.
+pub fn verify_all(inputs: &HashMap<usize, String>) -> anyhow::Result<usize> {
+    let config = Config::discover()?;
+    let year = config.year(None)?;
+    let mut outputs = outputs(inputs);
+    outputs.sort_by_key(|(day, _)| *day);
+    let mut failures = 0;
+    for (day, parts) in outputs {
+        let answers = Answers::load(&Answers::path(&config.cache_dir, year, day))?;
+        for (part, output) in [Part::A, Part::B].into_iter().zip(parts) {
+            let status = match output {
+                Ok(output) => {
+                    let verification = answers.verify(part, &output);
+                    if matches!(verification, Verification::Mismatch { .. }) {
+                        failures += 1;
+                    }
+                    format!("{output} ({verification})")
+                }
+                Err(err) => {
+                    failures += 1;
+                    format!("ERROR {err}")
+                }
+            };
+            println!("day-{day} {part}: {status}");
+        }
+    }
+    Ok(failures)
+}
@@ -0,0 +1,23 @@
+use aoc_client::block_on_input;
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+fn parse_benchmark(c: &mut Criterion) {
+    let input = block_on_input(None, 1);
+    let mut group = c.benchmark_group("day-1-parse");
+    group.bench_function("split_whitespace", |b| {
+        b.iter(|| {
+            black_box(&input)
+                .split_whitespace()
+                .flat_map(str::parse)
+                .collect::<Vec<i64>>()
+        });
+    });
+    group.bench_function("fast", |b| {
+        b.iter(|| parse::fast::ints(black_box(&input).as_bytes()).collect::<Vec<i64>>());
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parse_benchmark);
+criterion_main!(benches);
@@ -1,36 +1,35 @@
 pub mod solution {
     use std::collections::HashMap;
 
+    use parse::AocParse;
+
+    #[derive(AocParse)]
+    #[aoc(format = "{0} {1}")]
+    struct LocationPair(i64, i64);
+
     #[tracing::instrument(fields(input = format!("{:?}[...]", input.lines().next())))]
     pub fn part_a(input: &str) -> anyhow::Result<String> {
-        let (mut a, mut b): (Vec<_>, Vec<_>) = input
-            .lines()
-            .filter(|l| !l.is_empty())
-            .map(|l| {
-                let mut nums = l.split_whitespace().map(|w| w.parse::<isize>().unwrap());
-                (nums.next().unwrap(), nums.next().unwrap())
-            })
+        let (mut a, mut b): (Vec<_>, Vec<_>) = LocationPair::parse_lines(input)?
+            .into_iter()
+            .map(|LocationPair(a, b)| (a, b))
             .unzip();
         a.sort_unstable();
         b.sort_unstable();
-        let res: isize = a.iter().zip(&b).map(|(a, b)| (b - a).abs()).sum();
+        let res: i64 = a.iter().zip(&b).map(|(a, b)| (b - a).abs()).sum();
         Ok(res.to_string())
     }
 
     #[tracing::instrument(fields(input = format!("{:?}[...]", input.lines().next())))]
     pub fn part_b(input: &str) -> anyhow::Result<String> {
-        let (nums, counts) = input.lines().filter(|l| !l.is_empty()).fold(
+        let (nums, counts) = LocationPair::parse_lines(input)?.into_iter().fold(
             (Vec::new(), HashMap::new()),
-            |(mut nums, mut counts), l| {
-                let mut line_nums = l.split_whitespace().map(|w| w.parse::<isize>().unwrap());
-                let a = line_nums.next().unwrap();
+            |(mut nums, mut counts), LocationPair(a, b)| {
                 nums.push(a);
-                let b = line_nums.next().unwrap();
                 counts.entry(b).and_modify(|count| *count += 1).or_insert(1);
                 (nums, counts)
             },
         );
-        let res: isize = nums.iter().map(|n| n * counts.get(n).unwrap_or(&0)).sum();
+        let res: i64 = nums.iter().map(|n| n * counts.get(n).unwrap_or(&0)).sum();
         Ok(res.to_string())
     }
 }
@@ -4,7 +4,7 @@ use day_10::solution::part_a;
 use std::hint::black_box;
 
 fn part_a_benchmark(c: &mut Criterion) {
-    let input = block_on_input(10);
+    let input = block_on_input(None, 10);
     let mut group = c.benchmark_group("day-10");
     // group
     //     .sample_size(10)
@@ -1,13 +1,10 @@
 pub mod solution {
-    use count_digits::CountDigits;
-    use std::{cell::LazyCell, collections::HashMap};
+    use memo::Memo;
     use tracing::warn;
 
-    use math::POWERS_OF_10;
+    use math::digits::Digits;
 
-    #[derive(PartialEq, Eq, Hash)]
-    pub struct CacheKey(u64, u8);
-    type Cache = HashMap<CacheKey, u64>;
+    pub type Cache = Memo<(u64, u8), u64>;
 
     #[tracing::instrument(skip(input))]
     pub fn part_a(input: &str) -> anyhow::Result<String> {
@@ -23,41 +20,32 @@ pub mod solution {
         if iterations == 0 {
             return 1;
         }
-        let cache_key = CacheKey(num, iterations);
-        if let Some(res) = cache.get(&cache_key) {
-            return *res;
-        }
-
-        let rem_iter = iterations - 1;
-        let digits = LazyCell::new(|| num.count_digits());
-        let res = match num {
-            0 => eval_num(1, rem_iter, cache),
-            n if *digits % 2 == 0 => {
-                let digits = *digits;
-                let divisor = POWERS_OF_10[digits / 2];
-                let a = n / divisor;
-                let b = n % divisor;
-                eval_num(a, rem_iter, cache) + eval_num(b, rem_iter, cache)
+        cache.get_or_compute((num, iterations), |cache, (num, iterations)| {
+            let rem_iter = iterations - 1;
+            match num {
+                0 => eval_num(1, rem_iter, cache),
+                n => match n.split_half() {
+                    Some((a, b)) => eval_num(a, rem_iter, cache) + eval_num(b, rem_iter, cache),
+                    None => eval_num(n * 2024, rem_iter, cache),
+                },
             }
-            n => eval_num(n * 2024, rem_iter, cache),
-        };
-        cache.insert(cache_key, res);
-        res
+        })
     }
 
     pub fn eval(input: &str, iterations: u8) -> u64 {
-        input
+        let mut cache = Cache::new("stones");
+        let res = input
             .split_whitespace()
             .flat_map(str::parse)
-            .map(|num| eval_num(num, iterations, &mut HashMap::new()))
-            .sum()
+            .map(|num| eval_num(num, iterations, &mut cache))
+            .sum();
+        cache.trace_stats();
+        res
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
-
     use super::*;
     use test_case::test_case;
     use tracing_test::traced_test;
@@ -81,6 +69,6 @@ mod tests {
     #[test_case(99, 2 => 2)]
     #[traced_test]
     fn day_11_eval_num(num: u64, iterations: u8) -> u64 {
-        solution::eval_num(num, iterations, &mut HashMap::new())
+        solution::eval_num(num, iterations, &mut solution::Cache::new("stones"))
     }
 }
@@ -2,7 +2,10 @@ use std::env;
 
 use clap::Parser;
 
-use aoc_client::get_input;
+use aoc_client::{
+    blocking::{get_input, submit_answer, wait_for_input},
+    Backoff, Part,
+};
 use day_11::solution::{part_a, part_b};
 
 #[derive(Parser)]
@@ -11,19 +14,33 @@ struct Cli {
     /// Run part b
     #[arg(short)]
     b: bool,
+    /// Submit the answer
+    #[arg(short, long)]
+    submit: bool,
+    /// Wait for the puzzle to unlock and download the input right then
+    #[arg(short, long)]
+    wait: bool,
 }
 
-#[tokio::main]
 #[tracing::instrument]
-async fn main() -> anyhow::Result<()> {
+fn main() -> anyhow::Result<()> {
     tracing_subscriber::fmt::init();
     let cli = Cli::parse();
-    let input = get_input(env::current_dir()?, 11).await?;
+    let input = if cli.wait {
+        wait_for_input(env::current_dir()?, None, 11, Backoff::default())?
+    } else {
+        get_input(env::current_dir()?, None, 11)?
+    };
     let output = if cli.b {
         part_b(&input)?
     } else {
         part_a(&input)?
     };
     println!("{output}");
+    if cli.submit {
+        let part = if cli.b { Part::B } else { Part::A };
+        let result = submit_answer(None, 11, part, &output)?;
+        println!("{result}");
+    }
     Ok(())
 }
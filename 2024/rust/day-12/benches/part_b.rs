@@ -4,7 +4,7 @@ use day_12::solution::part_b;
 use std::hint::black_box;
 
 fn part_b_benchmark(c: &mut Criterion) {
-    let input = block_on_input(12);
+    let input = block_on_input(None, 12);
     let mut group = c.benchmark_group("day-12");
     // group
     //     .sample_size(10)
@@ -2,8 +2,7 @@ pub mod solution {
     use std::collections::{HashMap, HashSet};
 
     use glam::{IVec2, UVec2};
-    use grid::prelude::*;
-    use itertools::Itertools;
+    use grid::{geometry::count_corners, prelude::*};
     use tracing::warn;
 
     struct NeighbourCount(usize);
@@ -86,42 +85,7 @@ pub mod solution {
             let visited = map.drain_area(*pos);
             let visited: HashSet<_> = visited.keys().map(UVec2::as_ivec2).collect();
             let area = visited.len();
-            let edges: usize = visited
-                .iter()
-                // sort the tiles from top-left to btm-right
-                .sorted_unstable_by(|a, b| a.y.cmp(&b.y).then(a.x.cmp(&b.x)))
-                .map(|tile| {
-                    DIRS_4
-                        .iter()
-                        .filter(|dir| {
-                            let target = tile + *dir;
-                            match visited.get(&target) {
-                                // region tile in that direction => no edge
-                                Some(_) => false,
-                                _ => match **dir {
-                                    IVec2::NEG_Y => {
-                                        !visited.contains(&(tile + IVec2::NEG_X))
-                                            || visited.contains(&(tile + IVec2::NEG_ONE))
-                                    }
-                                    IVec2::Y => {
-                                        !visited.contains(&(tile + IVec2::NEG_X))
-                                            || visited.contains(&(tile + IVec2::new(-1, 1)))
-                                    }
-                                    IVec2::NEG_X => {
-                                        !visited.contains(&(tile + IVec2::NEG_Y))
-                                            || visited.contains(&(tile + IVec2::NEG_ONE))
-                                    }
-                                    IVec2::X => {
-                                        !visited.contains(&(tile + IVec2::NEG_Y))
-                                            || visited.contains(&(tile + IVec2::new(1, -1)))
-                                    }
-                                    _ => unreachable!(),
-                                },
-                            }
-                        })
-                        .count()
-                })
-                .sum();
+            let edges = count_corners(&visited);
             price += area * edges;
         }
         Ok(price.to_string())
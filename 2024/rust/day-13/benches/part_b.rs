@@ -4,7 +4,7 @@ use day_13::solution::part_b;
 use std::hint::black_box;
 
 fn part_b_benchmark(c: &mut Criterion) {
-    let input = block_on_input(13);
+    let input = block_on_input(None, 13);
     let mut group = c.benchmark_group("day-13");
     // group
     //     .sample_size(10)
@@ -1,11 +1,8 @@
 pub mod solution {
     use glam::U64Vec2;
-    use nom::{
-        bytes::complete::{tag, take_till1},
-        character::complete::{self},
-        sequence::{preceded, separated_pair},
-        IResult,
-    };
+    use math::linalg;
+    use nom::sequence::preceded;
+    use parse::IResultExt;
     use rayon::prelude::*;
 
     #[derive(Debug)]
@@ -16,29 +13,19 @@ pub mod solution {
     }
     impl Machine {
         fn token_count(&self, limit: Option<u32>) -> Option<u64> {
-            // use Cramer's rule
-            let determinant = Self::determinant_mat2(self.button_a, self.button_b);
-            let determinant_x = Self::determinant_mat2(self.prize, self.button_b);
-            let determinant_y = Self::determinant_mat2(self.button_a, self.prize);
-            if (determinant_x % determinant)
-                .abs()
-                .max((determinant_y % determinant).abs())
-                > 0
-            {
-                return None;
-            }
-            let x = (determinant_x / determinant) as u64;
-            let y = (determinant_y / determinant) as u64;
+            let (a, b, prize) = (
+                self.button_a.as_i64vec2(),
+                self.button_b.as_i64vec2(),
+                self.prize.as_i64vec2(),
+            );
+            let [x, y] = linalg::solve([[a.x, b.x], [a.y, b.y]], [prize.x, prize.y]).integer()?;
+            let (x, y) = (u64::try_from(x).ok()?, u64::try_from(y).ok()?);
             if limit.is_none_or(|limit| x.max(y) < u64::from(limit)) {
                 Some(x * 3 + y)
             } else {
                 None
             }
         }
-
-        fn determinant_mat2(row_a: U64Vec2, row_b: U64Vec2) -> i64 {
-            (row_a.x * row_b.y) as i64 - (row_b.x * row_a.y) as i64
-        }
     }
 
     #[tracing::instrument(skip(input))]
@@ -68,39 +55,15 @@ pub mod solution {
         Ok(token_count.to_string())
     }
 
-    fn parse_btn(input: &str) -> IResult<&str, U64Vec2> {
-        preceded(
-            till_incl_digit,
-            separated_pair(complete::u64, tag(", Y+"), complete::u64),
-        )(input)
-        .map(|(input, (x, y))| (input, U64Vec2::new(x, y)))
-    }
-
-    fn parse_prize(input: &str) -> IResult<&str, U64Vec2> {
-        preceded(
-            till_incl_digit,
-            separated_pair(complete::u64, tag(", Y="), complete::u64),
-        )(input)
-        .map(|(input, (x, y))| (input, U64Vec2::new(x, y)))
-    }
-
     fn parse_machine(lines: &[&str]) -> anyhow::Result<Machine> {
-        let (_, button_a) =
-            parse_btn(lines[0]).map_err(nom::Err::<nom::error::Error<&str>>::to_owned)?;
-        let (_, button_b) =
-            parse_btn(lines[1]).map_err(nom::Err::<nom::error::Error<&str>>::to_owned)?;
-        let (_, prize) =
-            parse_prize(lines[2]).map_err(nom::Err::<nom::error::Error<&str>>::to_owned)?;
+        let parse_line =
+            |line: &str| preceded(parse::label, parse::axis_vec)(line).into_anyhow(line);
         Ok(Machine {
-            button_a,
-            button_b,
-            prize,
+            button_a: parse_line(lines[0])?,
+            button_b: parse_line(lines[1])?,
+            prize: parse_line(lines[2])?,
         })
     }
-
-    fn till_incl_digit(input: &str) -> IResult<&str, &str> {
-        take_till1(|c: char| c.is_ascii_digit())(input)
-    }
 }
 
 #[cfg(test)]
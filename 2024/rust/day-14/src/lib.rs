@@ -5,12 +5,8 @@ pub mod solution {
     };
 
     use glam::{IVec2, UVec2};
-    use nom::{
-        bytes::complete::tag,
-        character::complete::space1,
-        sequence::{preceded, separated_pair},
-        Parser,
-    };
+    use nom::{character::complete::space1, sequence::separated_pair, Parser};
+    use parse::IResultExt;
 
     type QuadrantRange = (Range<u32>, Range<u32>);
     struct Map {
@@ -46,18 +42,13 @@ pub mod solution {
     }
     impl Robot {
         pub fn parse(input: &str) -> anyhow::Result<Self> {
-            let (_, robot) = separated_pair(
-                preceded(tag("p="), parse::parse_ivec2),
-                space1,
-                preceded(tag("v="), parse::parse_ivec2),
-            )
-            .map(|(position, velocity)| Self {
-                position: position.as_uvec2(),
-                velocity,
-            })
-            .parse(input)
-            .map_err(nom::Err::<nom::error::Error<&str>>::to_owned)?;
-            Ok(robot)
+            separated_pair(parse::labelled_vec("p="), space1, parse::labelled_vec("v="))
+                .map(|(position, velocity): (IVec2, _)| Self {
+                    position: position.as_uvec2(),
+                    velocity,
+                })
+                .parse(input)
+                .into_anyhow(input)
         }
 
         pub fn step(&self, step_count: u32, map: &Map) -> UVec2 {
@@ -4,7 +4,7 @@ use day_15::solution::part_b;
 use std::hint::black_box;
 
 fn part_b_benchmark(c: &mut Criterion) {
-    let input = block_on_input(15);
+    let input = block_on_input(None, 15);
     let mut group = c.benchmark_group("day-15");
     // group
     //     .sample_size(10)
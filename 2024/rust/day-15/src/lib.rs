@@ -39,21 +39,30 @@ pub mod solution {
 
     #[tracing::instrument(skip(input))]
     pub fn part_a(input: &str) -> anyhow::Result<String> {
-        let mut lines = input.lines();
         let mut crates = HashSet::new();
-        let built_grid = GridBuilder::build_obstacle_grid_from_lines_with_processing()
-            .lines(lines.by_ref())
-            .obstacle('#')
-            .start_character('@')
-            .process_ctx(&mut crates)
-            .process_tile(|crates, c, tile| match c {
-                'O' => {
-                    crates.insert(tile);
-                    true
-                }
-                _ => false,
-            })
-            .call()?;
+        let (built_grid, moves) = parse::parse_sections(
+            input,
+            (
+                |warehouse: &str| {
+                    Ok(
+                        GridBuilder::build_obstacle_grid_from_lines_with_processing()
+                            .lines(&mut warehouse.lines())
+                            .obstacle('#')
+                            .start_character('@')
+                            .process_ctx(&mut crates)
+                            .process_tile(|crates, c, tile| match c {
+                                'O' => {
+                                    crates.insert(tile);
+                                    true
+                                }
+                                _ => false,
+                            })
+                            .call()?,
+                    )
+                },
+                parse_moves,
+            ),
+        )?;
         let BuiltGrid::<()> {
             grid,
             start_tile: Some(robot_tile),
@@ -67,17 +76,8 @@ pub mod solution {
             crates,
             robot_tile,
         };
-        for l in lines {
-            for dir_c in l.chars() {
-                let dir = match dir_c {
-                    '^' => IVec2::NEG_Y,
-                    '>' => IVec2::X,
-                    'v' => IVec2::Y,
-                    '<' => IVec2::NEG_X,
-                    _ => unreachable!(),
-                };
-                map.move_robot(dir);
-            }
+        for dir in moves {
+            map.move_robot(dir);
         }
         let score: u32 = map
             .crates
@@ -206,34 +206,30 @@ pub mod solution {
 
     #[tracing::instrument(skip(input))]
     pub fn part_b(input: &str) -> anyhow::Result<String> {
-        let mut lines = input.lines();
-        let (Some(robot_tile), obstacles, crates, size) = lines
-            .by_ref()
-            .take_while(|l| !l.is_empty())
-            .enumerate()
-            .fold(
-                (None, HashSet::new(), HashMap::new(), UVec2::ZERO),
-                |(mut robot_tile, mut obstacles, mut crates, _), (y, l)| {
-                    let mut size = UVec2::ZERO;
-                    for (x, c) in l.chars().enumerate() {
-                        let tile_l = UVec2::new(x as u32 * 2, y as _);
-                        let tile_r = tile_l + UVec2::X;
-                        size = tile_r + UVec2::ONE;
-                        match c {
-                            '@' => robot_tile = Some(tile_l),
-                            'O' => {
-                                crates.extend([(tile_l, tile_r), (tile_r, tile_l)]);
-                            }
-                            '#' => {
-                                obstacles.extend([tile_l, tile_r]);
-                            }
-                            _ => {}
+        let (warehouse, moves) =
+            parse::parse_sections(input, (|warehouse| Ok(warehouse), parse_moves))?;
+        let (Some(robot_tile), obstacles, crates, size) = warehouse.lines().enumerate().fold(
+            (None, HashSet::new(), HashMap::new(), UVec2::ZERO),
+            |(mut robot_tile, mut obstacles, mut crates, _), (y, l)| {
+                let mut size = UVec2::ZERO;
+                for (x, c) in l.chars().enumerate() {
+                    let tile_l = UVec2::new(x as u32 * 2, y as _);
+                    let tile_r = tile_l + UVec2::X;
+                    size = tile_r + UVec2::ONE;
+                    match c {
+                        '@' => robot_tile = Some(tile_l),
+                        'O' => {
+                            crates.extend([(tile_l, tile_r), (tile_r, tile_l)]);
+                        }
+                        '#' => {
+                            obstacles.extend([tile_l, tile_r]);
                         }
+                        _ => {}
                     }
-                    (robot_tile, obstacles, crates, size)
-                },
-            )
-        else {
+                }
+                (robot_tile, obstacles, crates, size)
+            },
+        ) else {
             panic!("Invalid map - robot not found")
         };
         let mut map = MapB {
@@ -242,18 +238,9 @@ pub mod solution {
             robot_tile,
         };
         // println!("Start:\n{}\n", map.debug_map());
-        for l in lines {
-            for dir_c in l.chars() {
-                let dir = match dir_c {
-                    '^' => IVec2::NEG_Y,
-                    '>' => IVec2::X,
-                    'v' => IVec2::Y,
-                    '<' => IVec2::NEG_X,
-                    _ => unreachable!(),
-                };
-                map.move_robot(dir);
-                // println!("Move {dir_c}:\n{}\n", map.debug_map());
-            }
+        for dir in moves {
+            map.move_robot(dir);
+            // println!("Move {dir}:\n{}\n", map.debug_map());
         }
         // println!("Done:\n{}\n", map.debug_map());
         let score: u32 = map
@@ -264,6 +251,20 @@ pub mod solution {
             .sum();
         Ok(score.to_string())
     }
+
+    fn parse_moves(moves: &str) -> anyhow::Result<Vec<IVec2>> {
+        moves
+            .lines()
+            .flat_map(str::chars)
+            .map(|dir_c| match dir_c {
+                '^' => Ok(IVec2::NEG_Y),
+                '>' => Ok(IVec2::X),
+                'v' => Ok(IVec2::Y),
+                '<' => Ok(IVec2::NEG_X),
+                _ => Err(anyhow::anyhow!("Invalid move '{dir_c}'")),
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -3,6 +3,7 @@ pub mod solution {
 
     use glam::UVec2;
     use grid::prelude::*;
+    use parse::AocParse;
 
     #[tracing::instrument(skip(input))]
     pub fn part_a(input: &str) -> anyhow::Result<String> {
@@ -14,7 +15,7 @@ pub mod solution {
         let obstacles: HashSet<_> = input
             .lines()
             .take(byte_count)
-            .flat_map(parse::parse_uvec2_res)
+            .flat_map(UVec2::parse_str)
             .collect();
         let grid = Grid::<()>::from_obstacles(obstacles, (size, size));
         let path = grid
@@ -31,7 +32,7 @@ pub mod solution {
 
     pub(crate) fn solve_b(input: &str, size: u8, safe_byte_count: usize) -> UVec2 {
         let size = u32::from(size);
-        let walls: Vec<_> = input.lines().flat_map(parse::parse_uvec2_res).collect();
+        let walls: Vec<_> = input.lines().flat_map(UVec2::parse_str).collect();
         let mut floor = safe_byte_count + 1;
         let mut ceil = walls.len() - 1;
         loop {
@@ -4,7 +4,7 @@ pub mod solution {
         let count = input
             .lines()
             .filter(|l| {
-                let nums: Vec<i64> = l.split_whitespace().flat_map(str::parse).collect();
+                let nums: Vec<i64> = parse::fast::ints(l.as_bytes()).collect();
                 let mut inc = None;
                 nums.windows(2).all(|nums| {
                     let delta = nums[1] - nums[0];
@@ -80,7 +80,7 @@ pub mod solution {
         let count = input
             .lines()
             .filter(|l| {
-                let nums: Vec<i64> = l.split_whitespace().flat_map(str::parse).collect();
+                let nums: Vec<i64> = parse::fast::ints(l.as_bytes()).collect();
                 report_valid(&nums, false)
             })
             .count();
@@ -0,0 +1,23 @@
+use aoc_client::block_on_input;
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+fn parse_benchmark(c: &mut Criterion) {
+    let input = block_on_input(None, 22);
+    let mut group = c.benchmark_group("day-22-parse");
+    group.bench_function("lines", |b| {
+        b.iter(|| {
+            black_box(&input)
+                .lines()
+                .flat_map(str::parse)
+                .collect::<Vec<usize>>()
+        });
+    });
+    group.bench_function("fast", |b| {
+        b.iter(|| parse::fast::ints(black_box(&input).as_bytes()).collect::<Vec<usize>>());
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parse_benchmark);
+criterion_main!(benches);
@@ -4,7 +4,7 @@ use day_22::solution::part_a;
 use std::hint::black_box;
 
 fn part_a_benchmark(c: &mut Criterion) {
-    let input = block_on_input(22);
+    let input = block_on_input(None, 22);
     let mut group = c.benchmark_group("day-22");
     // group
     //     .sample_size(10)
@@ -1,15 +1,14 @@
 pub mod solution {
-    use std::collections::{HashMap, HashSet};
-
-    use itertools::Itertools;
+    use anyhow::Context;
+    use math::WindowAggregator;
     use rayon::prelude::*;
 
     const STEP_COUNT: u32 = 2000;
 
     #[tracing::instrument(skip(input))]
     pub fn part_a(input: &str) -> anyhow::Result<String> {
-        let nums = parse_nums(input);
-        let res: usize = nums
+        let seeds = parse_seeds(input);
+        let res: u64 = seeds
             .into_par_iter()
             .map(|n| (0..STEP_COUNT).fold(n, |acc, _| secret_number(acc)))
             .sum();
@@ -18,66 +17,38 @@ pub mod solution {
 
     #[tracing::instrument(skip(input))]
     pub fn part_b(input: &str) -> anyhow::Result<String> {
-        let res = solve_b(input, STEP_COUNT);
+        let res = solve_b(input, STEP_COUNT)?;
         Ok(res.to_string())
     }
 
-    pub(crate) fn solve_b(input: &str, step_count: u32) -> usize {
-        let nums = parse_nums(input);
-        let monkey_sequences: Vec<_> = nums
-            .into_par_iter()
-            .map(|n| {
-                let mut acc = Vec::with_capacity(step_count as _);
-                acc.push((n % 10) as u8);
-                (0..step_count).fold((n, acc), |(num, mut units), _| {
-                    let num = secret_number(num);
-                    let num_units = num % 10;
-                    units.push(num_units as u8);
-                    (num, units)
-                })
-            })
-            .map(|(_, units)| {
-                let capacity = units.len();
-                units
-                    .windows(5)
-                    .fold(HashMap::with_capacity(capacity), |mut acc, sequence| {
-                        let seq: (_, _, _, _) = sequence
-                            .windows(2)
-                            .map(|win| win[0] as i8 - win[1] as i8)
-                            .collect_tuple()
-                            .expect("Valid sequence tuple");
-                        acc.entry(seq).or_insert(*sequence.last().unwrap());
-                        acc
-                    })
-            })
-            .collect();
-        let all_sequences: HashSet<_> = monkey_sequences
-            .iter()
-            .flat_map(|monkey| monkey.keys())
-            .collect();
-        all_sequences
-            .into_par_iter()
-            .map(|seq| {
-                monkey_sequences
-                    .iter()
-                    .map(|monkey| *monkey.get(seq).unwrap_or(&0) as usize)
-                    .sum::<usize>()
-            })
-            .max()
-            .expect("At least one sequence")
+    pub(crate) fn solve_b(input: &str, step_count: u32) -> anyhow::Result<usize> {
+        let seeds = parse_seeds(input);
+        // price changes are within -9..=9, so 4 of them pack into 19^4 keys
+        let totals = WindowAggregator::new(4, 9).aggregate(seeds.into_par_iter().map(|n| {
+            secret_numbers(n)
+                .take(step_count as usize + 1)
+                .map(|num| (num % 10) as i64)
+        }));
+        let (_, bananas) = totals.best().context("Found a price change sequence")?;
+        Ok(bananas as usize)
+    }
+
+    /// The pseudo-random sequence of secret numbers starting with the `seed`.
+    fn secret_numbers(seed: u64) -> impl Iterator<Item = u64> {
+        std::iter::successors(Some(seed), |num| Some(secret_number(*num)))
     }
 
-    fn parse_nums(input: &str) -> Vec<usize> {
-        input.lines().flat_map(str::parse).collect()
+    fn parse_seeds(input: &str) -> Vec<u64> {
+        parse::fast::ints(input.as_bytes()).collect()
     }
 
-    pub(crate) fn secret_number(num: usize) -> usize {
+    pub(crate) fn secret_number(num: u64) -> u64 {
         let num_1 = mix_and_prune(num << 6, num);
         let num_2 = mix_and_prune(num_1 >> 5, num_1);
         mix_and_prune(num_2 << 11, num_2)
     }
 
-    fn mix_and_prune(num: usize, secret: usize) -> usize {
+    fn mix_and_prune(num: u64, secret: u64) -> u64 {
         (num ^ secret) % 16_777_216
     }
 }
@@ -90,7 +61,7 @@ mod tests {
 
     #[test_case(123 => 15_887_950)]
     #[traced_test]
-    fn day_22_secret_number(num: usize) -> usize {
+    fn day_22_secret_number(num: u64) -> u64 {
         solution::secret_number(num)
     }
 
@@ -112,6 +83,6 @@ mod tests {
     #[traced_test]
     fn day_22_b_simple() {
         let res = solution::solve_b("123", 10);
-        assert_eq!(6, res);
+        assert_eq!(6, res.unwrap());
     }
 }
@@ -4,7 +4,7 @@ use day_25::solution::part_b;
 use std::hint::black_box;
 
 fn part_b_benchmark(c: &mut Criterion) {
-    let input = block_on_input(25);
+    let input = block_on_input(None, 25);
     let mut group = c.benchmark_group("day-25");
     // group
     //     .sample_size(10)
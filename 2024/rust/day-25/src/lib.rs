@@ -2,23 +2,18 @@ pub mod solution {
     // note: tried rayon to paralellize the parsing and the combo count but it was  slower or just very slightly faster respectively
     #[tracing::instrument(skip(input))]
     pub fn part_a(input: &str) -> anyhow::Result<String> {
-        let lines: Vec<_> = input.lines().collect();
-        let parsed_chunks: Vec<_> = lines
-            .chunks(8)
-            .map(|chunk_lines| {
-                let is_lock = chunk_lines[0].starts_with('#');
-                let pins = chunk_lines
-                    .iter()
-                    .skip(1)
-                    .take(5)
-                    .fold([0u8; 5], |mut acc, l| {
-                        for (i, c) in l.chars().enumerate() {
-                            if c == '#' {
-                                acc[i] += 1;
-                            }
+        let parsed_chunks: Vec<_> = parse::chunks(input, 7)
+            .map(|chunk| {
+                let mut chunk_lines = chunk.lines();
+                let is_lock = chunk_lines.next().is_some_and(|l| l.starts_with('#'));
+                let pins = chunk_lines.take(5).fold([0u8; 5], |mut acc, l| {
+                    for (i, c) in l.chars().enumerate() {
+                        if c == '#' {
+                            acc[i] += 1;
                         }
-                        acc
-                    });
+                    }
+                    acc
+                });
                 (pins, is_lock)
             })
             .collect();
@@ -4,7 +4,7 @@ use day_3::solution::part_b;
 use std::hint::black_box;
 
 fn part_b_benchmark(c: &mut Criterion) {
-    let input = block_on_input(3);
+    let input = block_on_input(None, 3);
     c.bench_function("part_b", |b| b.iter(|| part_b(black_box(&input))));
 }
 
@@ -4,7 +4,7 @@ use day_4::solution::part_a;
 use std::hint::black_box;
 
 fn part_a_benchmark(c: &mut Criterion) {
-    let input = block_on_input(4);
+    let input = block_on_input(None, 4);
     c.bench_function("part_a", |b| b.iter(|| part_a(black_box(&input))));
 }
 
@@ -0,0 +1,31 @@
+use aoc_client::block_on_input;
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+fn parse_benchmark(c: &mut Criterion) {
+    let input = block_on_input(None, 7);
+    let mut group = c.benchmark_group("day-7-parse");
+    group.bench_function("split_whitespace", |b| {
+        b.iter(|| {
+            black_box(&input)
+                .lines()
+                .map(|l| {
+                    l.split_whitespace()
+                        .flat_map(|n| n.trim_end_matches(':').parse::<u64>())
+                        .sum::<u64>()
+                })
+                .sum::<u64>()
+        });
+    });
+    group.bench_function("fast", |b| {
+        b.iter(|| {
+            parse::fast::lines(black_box(&input).as_bytes())
+                .map(|l| parse::fast::ints::<u64>(l).sum::<u64>())
+                .sum::<u64>()
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parse_benchmark);
+criterion_main!(benches);
@@ -4,7 +4,7 @@ use day_7::solution::part_a;
 use std::hint::black_box;
 
 fn part_a_benchmark(c: &mut Criterion) {
-    let input = block_on_input(7);
+    let input = block_on_input(None, 7);
     let mut group = c.benchmark_group("day-7");
     group.bench_function("part_a", |b| b.iter(|| part_a(black_box(&input))));
     group.finish();
@@ -1,9 +1,16 @@
 pub mod solution {
-    use count_digits::CountDigits;
-    use math::POWERS_OF_10;
+    use math::{digits::Digits, Inverse, OperatorSearch};
+    use parse::AocParse;
     use rayon::prelude::*;
     use tracing::warn;
 
+    #[derive(AocParse)]
+    #[aoc(format = "{total}: {nums: }")]
+    struct Equation {
+        total: u64,
+        nums: Vec<u64>,
+    }
+
     #[derive(Debug, Clone, Copy)]
     enum Operation {
         Addition,
@@ -13,59 +20,47 @@ pub mod solution {
 
     #[tracing::instrument(skip(input))]
     pub fn part_a(input: &str) -> anyhow::Result<String> {
-        eval(input, &[Operation::Addition, Operation::Multiplication])
+        eval(input, &operator_search(false))
     }
 
     #[tracing::instrument(fields(input = format!("{:?}[...]", input.lines().next())))]
     pub fn part_b(input: &str) -> anyhow::Result<String> {
-        eval(
-            input,
-            &[
-                Operation::Addition,
-                Operation::Multiplication,
-                Operation::Concat,
-            ],
-        )
+        eval(input, &operator_search(true))
+    }
+
+    fn operator_search(with_concat: bool) -> OperatorSearch<u64, Operation> {
+        let search = OperatorSearch::new()
+            .operator(Operation::Addition, u64::checked_add)
+            .with_inverse(u64::checked_sub)
+            .operator(Operation::Multiplication, u64::checked_mul)
+            .with_inverse(|total, num| match (total, num) {
+                (0, 0) => Inverse::Any,
+                (_, 0) => Inverse::Impossible,
+                _ => (total % num == 0).then(|| total / num).into(),
+            });
+        if !with_concat {
+            return search;
+        }
+        search
+            .operator(Operation::Concat, Digits::checked_concat)
+            .with_inverse(
+                |total: u64, num: u64| match total.split_at_digit(num.digit_count()) {
+                    (prefix, suffix) if suffix == num => Some(prefix),
+                    _ => None,
+                },
+            )
     }
 
     #[tracing::instrument(skip_all)]
-    pub fn eval(input: &str, operations: &[Operation]) -> anyhow::Result<String> {
-        let lines: Vec<_> = input.lines().collect();
-        let sum: u64 = lines
+    fn eval(input: &str, search: &OperatorSearch<u64, Operation>) -> anyhow::Result<String> {
+        let equations = Equation::parse_lines(input)?;
+        let sum: u64 = equations
             .into_par_iter()
-            .filter_map(|l| {
-                let (total, nums) = l.split_once(':').expect("Valid example line");
-                let total: u64 = total.parse().expect("Valid total number");
-                let nums: Vec<u64> = nums.split_whitespace().flat_map(str::parse).collect();
-                eval_ops(nums[0], total, 0, &nums, operations)
-            })
+            .filter(|Equation { total, nums }| search.solve(nums, *total).is_some())
+            .map(|Equation { total, .. }| total)
             .sum();
         Ok(sum.to_string())
     }
-
-    fn eval_ops(
-        val: u64,
-        target_total: u64,
-        i: usize,
-        nums: &[u64],
-        ops: &[Operation],
-    ) -> Option<u64> {
-        if i == nums.len() - 1 {
-            return (val == target_total).then_some(val);
-        }
-        let next = nums[i + 1];
-        ops.iter().find_map(|op| match op {
-            Operation::Addition => eval_ops(val + next, target_total, i + 1, nums, ops),
-            Operation::Multiplication => eval_ops(val * next, target_total, i + 1, nums, ops),
-            Operation::Concat => eval_ops(
-                val * POWERS_OF_10[next.count_digits()] + next,
-                target_total,
-                i + 1,
-                nums,
-                ops,
-            ),
-        })
-    }
 }
 
 #[cfg(test)]
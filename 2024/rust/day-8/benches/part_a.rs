@@ -4,7 +4,7 @@ use day_8::solution::part_a;
 use std::hint::black_box;
 
 fn part_a_benchmark(c: &mut Criterion) {
-    let input = block_on_input(8);
+    let input = block_on_input(None, 8);
     let mut group = c.benchmark_group("day-8");
     // group
     //     .sample_size(10)
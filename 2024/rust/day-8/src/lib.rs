@@ -86,26 +86,20 @@ pub mod solution {
                         (a2, a1)
                     };
                     let delta = a2.as_ivec2() - a1.as_ivec2();
+                    // smallest step between grid tiles on the line through both antennas
+                    let step = delta / math::gcd(delta.x, delta.y);
                     let mut antinodes = Vec::with_capacity(vec_capacity);
                     // up from earlier/higher antinode
                     let mut an = a1.as_ivec2();
-                    loop {
-                        if map.contains_ivec2_coords(an) {
-                            antinodes.push(an);
-                        } else {
-                            break;
-                        }
-                        an -= delta;
+                    while map.contains_ivec2_coords(an) {
+                        antinodes.push(an);
+                        an -= step;
                     }
-                    // up from earlier/higher antinode
-                    let mut an = a2.as_ivec2();
-                    loop {
-                        if map.contains_ivec2_coords(an) {
-                            antinodes.push(an);
-                        } else {
-                            break;
-                        }
-                        an += delta;
+                    // down from earlier/higher antinode through the later/lower one
+                    let mut an = a1.as_ivec2() + step;
+                    while map.contains_ivec2_coords(an) {
+                        antinodes.push(an);
+                        an += step;
                     }
                     antinodes.into_iter().map(|an| an.as_uvec2())
                 })
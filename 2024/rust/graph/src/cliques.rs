@@ -0,0 +1,171 @@
+use std::{collections::HashSet, hash::Hash};
+
+use crate::{Graph, NodeId};
+
+/// Clique searches, they expect undirected graphs (self loops are ignored).
+impl<K: Hash + Eq + Clone> Graph<K> {
+    /// All the triangles as sorted id triples, each one listed once.
+    #[must_use]
+    pub fn triangles(&self) -> Vec<[NodeId; 3]> {
+        let mut triangles = Vec::new();
+        for a in self.nodes() {
+            for &b in self.neighbours(a).iter().filter(|b| **b > a) {
+                for &c in self.neighbours(b).iter().filter(|c| **c > b) {
+                    if self.has_edge(a, c) {
+                        triangles.push([a, b, c]);
+                    }
+                }
+            }
+        }
+        triangles.sort_unstable();
+        triangles
+    }
+
+    /// All the maximal cliques (ids sorted) by Bron–Kerbosch with pivoting.
+    #[must_use]
+    pub fn maximal_cliques(&self) -> Vec<Vec<NodeId>> {
+        let mut cliques = Vec::new();
+        self.bron_kerbosch(
+            &mut Vec::new(),
+            self.nodes().collect(),
+            HashSet::new(),
+            &mut 0,
+            &mut |clique| {
+                let mut clique = clique.to_vec();
+                clique.sort_unstable();
+                cliques.push(clique);
+                0
+            },
+        );
+        cliques.sort_unstable();
+        cliques
+    }
+
+    /// The largest clique (ids sorted), branches that can't beat the best clique found so far are skipped.
+    #[must_use]
+    pub fn max_clique(&self) -> Vec<NodeId> {
+        let mut best = Vec::new();
+        self.bron_kerbosch(
+            &mut Vec::new(),
+            self.nodes().collect(),
+            HashSet::new(),
+            &mut 0,
+            &mut |clique| {
+                if clique.len() > best.len() {
+                    best = clique.to_vec();
+                }
+                best.len() + 1
+            },
+        );
+        best.sort_unstable();
+        best
+    }
+
+    // `visit` gets each maximal clique of at least `min_size` nodes and returns the new `min_size`
+    fn bron_kerbosch(
+        &self,
+        clique: &mut Vec<NodeId>,
+        mut candidates: HashSet<NodeId>,
+        mut excluded: HashSet<NodeId>,
+        min_size: &mut usize,
+        visit: &mut impl FnMut(&[NodeId]) -> usize,
+    ) {
+        if candidates.is_empty() {
+            if excluded.is_empty() && clique.len() >= *min_size {
+                *min_size = visit(clique);
+            }
+            return;
+        }
+        if clique.len() + candidates.len() < *min_size {
+            return;
+        }
+        // the pivot's neighbours are covered by the branch of the pivot (or one of its non-neighbours)
+        let pivot = candidates
+            .union(&excluded)
+            .max_by_key(|node| self.neighbours(**node).intersection(&candidates).count())
+            .copied()
+            .expect("Non-empty candidates");
+        let pivot_neighbours = self.neighbours(pivot);
+        let branches: Vec<_> = candidates
+            .iter()
+            .filter(|node| !pivot_neighbours.contains(node))
+            .copied()
+            .collect();
+        for node in branches {
+            let neighbours = self.neighbours(node);
+            let within = |set: &HashSet<NodeId>| {
+                set.iter()
+                    .filter(|n| **n != node && neighbours.contains(n))
+                    .copied()
+                    .collect()
+            };
+            clique.push(node);
+            self.bron_kerbosch(
+                clique,
+                within(&candidates),
+                within(&excluded),
+                min_size,
+                visit,
+            );
+            clique.pop();
+            candidates.remove(&node);
+            excluded.insert(node);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the day-23 example network
+    const NETWORK: &str =
+        "kh-tc qp-kh de-cg ka-co yn-aq qp-ub cg-tb vc-aq tb-ka wh-tc yn-cg kh-ub \
+        ta-co de-co tc-td tb-wq wh-td ta-ka td-qp aq-cg wq-ub ub-vc de-ta wq-aq wq-vc wh-yn ka-de \
+        kh-ta co-tc wh-qp tb-vc td-yn";
+
+    fn network() -> Graph<&'static str> {
+        Graph::from_undirected_edges(
+            NETWORK
+                .split_whitespace()
+                .map(|edge| edge.split_once('-').unwrap()),
+        )
+    }
+
+    fn names(graph: &Graph<&'static str>, ids: &[NodeId]) -> String {
+        let mut names: Vec<_> = graph.keys(ids.iter().copied()).copied().collect();
+        names.sort_unstable();
+        names.join(",")
+    }
+
+    #[test]
+    fn triangles() {
+        let graph = network();
+        let triangles = graph.triangles();
+        assert_eq!(12, triangles.len());
+        let with_t = triangles
+            .iter()
+            .filter(|t| graph.keys(t.iter().copied()).any(|n| n.starts_with('t')))
+            .count();
+        assert_eq!(7, with_t);
+    }
+
+    #[test]
+    fn max_clique() {
+        let graph = network();
+        assert_eq!("co,de,ka,ta", names(&graph, &graph.max_clique()));
+    }
+
+    #[test]
+    fn maximal_cliques() {
+        // two triangles sharing an edge plus a separate edge
+        let graph = Graph::from_undirected_edges([(1, 2), (2, 3), (3, 1), (2, 4), (3, 4), (5, 6)]);
+        let cliques: Vec<Vec<_>> = graph
+            .maximal_cliques()
+            .iter()
+            .map(|c| graph.keys(c.iter().copied()).copied().collect())
+            .collect();
+        assert_eq!(vec![vec![1, 2, 3], vec![2, 3, 4], vec![5, 6]], cliques);
+        assert!(Graph::<u8>::undirected().max_clique().is_empty());
+    }
+}
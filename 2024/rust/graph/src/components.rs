@@ -0,0 +1,55 @@
+use std::hash::Hash;
+
+use crate::{Graph, NodeId};
+
+impl<K: Hash + Eq + Clone> Graph<K> {
+    /// Connected components (ignoring the edge directions), each sorted and ordered by their smallest id.
+    #[must_use]
+    pub fn connected_components(&self) -> Vec<Vec<NodeId>> {
+        let mut parent: Vec<_> = self.nodes().collect();
+        for node in self.nodes() {
+            for next in self.neighbours(node) {
+                let (a, b) = (root(&mut parent, node), root(&mut parent, *next));
+                parent[a.max(b)] = a.min(b);
+            }
+        }
+        let mut components: Vec<Vec<NodeId>> = Vec::new();
+        let mut component_of: Vec<Option<usize>> = vec![None; self.node_count()];
+        for node in self.nodes() {
+            let root = root(&mut parent, node);
+            if let Some(i) = component_of[root] {
+                components[i].push(node);
+            } else {
+                component_of[root] = Some(components.len());
+                components.push(vec![node]);
+            }
+        }
+        components
+    }
+}
+
+// union-find root lookup with path halving
+fn root(parent: &mut [NodeId], mut node: NodeId) -> NodeId {
+    while parent[node] != node {
+        parent[node] = parent[parent[node]];
+        node = parent[node];
+    }
+    node
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn components() {
+        let mut graph = Graph::from_directed_edges([("a", "b"), ("c", "b"), ("d", "e")]);
+        graph.add_node("f");
+        assert_eq!(
+            vec![vec![0, 1, 2], vec![3, 4], vec![5]],
+            graph.connected_components()
+        );
+        let graph = Graph::from_undirected_edges([(1, 2), (3, 4), (2, 3)]);
+        assert_eq!(1, graph.connected_components().len());
+    }
+}
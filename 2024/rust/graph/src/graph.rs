@@ -0,0 +1,149 @@
+use std::{collections::HashSet, hash::Hash};
+
+use crate::{Interner, NodeId};
+
+/// Adjacency set graph over interned keys (e.g. `&str` names or integers).
+#[derive(Debug, Clone)]
+pub struct Graph<K> {
+    nodes: Interner<K>,
+    adjacency: Vec<HashSet<NodeId>>,
+    directed: bool,
+    edge_count: usize,
+}
+impl<K: Hash + Eq + Clone> Graph<K> {
+    #[must_use]
+    pub fn directed() -> Self {
+        Self::new(true)
+    }
+
+    #[must_use]
+    pub fn undirected() -> Self {
+        Self::new(false)
+    }
+
+    fn new(directed: bool) -> Self {
+        Self {
+            nodes: Interner::default(),
+            adjacency: Vec::new(),
+            directed,
+            edge_count: 0,
+        }
+    }
+
+    /// Undirected graph from `(a, b)` pairs.
+    pub fn from_undirected_edges(edges: impl IntoIterator<Item = (K, K)>) -> Self {
+        let mut graph = Self::undirected();
+        graph.extend(edges);
+        graph
+    }
+
+    /// Directed graph from `(from, to)` pairs.
+    pub fn from_directed_edges(edges: impl IntoIterator<Item = (K, K)>) -> Self {
+        let mut graph = Self::directed();
+        graph.extend(edges);
+        graph
+    }
+
+    pub fn add_node(&mut self, key: K) -> NodeId {
+        let id = self.nodes.intern(key);
+        if id == self.adjacency.len() {
+            self.adjacency.push(HashSet::new());
+        }
+        id
+    }
+
+    /// Adds the edge (both ways for undirected graphs), returns the ids of its ends.
+    pub fn add_edge(&mut self, from: K, to: K) -> (NodeId, NodeId) {
+        let (from, to) = (self.add_node(from), self.add_node(to));
+        if self.adjacency[from].insert(to) {
+            self.edge_count += 1;
+            if !self.directed {
+                self.adjacency[to].insert(from);
+            }
+        }
+        (from, to)
+    }
+
+    #[must_use]
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    #[must_use]
+    pub fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    /// Number of edges, an undirected edge is counted once.
+    #[must_use]
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> {
+        0..self.node_count()
+    }
+
+    #[must_use]
+    pub fn id(&self, key: &K) -> Option<NodeId> {
+        self.nodes.id(key)
+    }
+
+    #[must_use]
+    pub fn key(&self, id: NodeId) -> &K {
+        self.nodes.key(id)
+    }
+
+    /// Outgoing neighbours (all neighbours for undirected graphs).
+    #[must_use]
+    pub fn neighbours(&self, id: NodeId) -> &HashSet<NodeId> {
+        &self.adjacency[id]
+    }
+
+    #[must_use]
+    pub fn has_edge(&self, from: NodeId, to: NodeId) -> bool {
+        self.adjacency[from].contains(&to)
+    }
+
+    /// Maps ids back to their keys.
+    pub fn keys<'a>(
+        &'a self,
+        ids: impl IntoIterator<Item = NodeId> + 'a,
+    ) -> impl Iterator<Item = &'a K> {
+        ids.into_iter().map(|id| self.key(id))
+    }
+}
+impl<K: Hash + Eq + Clone> Extend<(K, K)> for Graph<K> {
+    fn extend<T: IntoIterator<Item = (K, K)>>(&mut self, edges: T) {
+        for (from, to) in edges {
+            self.add_edge(from, to);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undirected() {
+        let graph = Graph::from_undirected_edges([("kh", "tc"), ("qp", "kh"), ("tc", "kh")]);
+        assert_eq!(3, graph.node_count());
+        assert_eq!(2, graph.edge_count());
+        let (kh, tc) = (graph.id(&"kh").unwrap(), graph.id(&"tc").unwrap());
+        assert!(graph.has_edge(kh, tc) && graph.has_edge(tc, kh));
+        assert_eq!(2, graph.neighbours(kh).len());
+    }
+
+    #[test]
+    fn directed() {
+        let mut graph = Graph::directed();
+        graph.extend([(47, 53), (97, 13), (97, 47)]);
+        graph.add_node(5);
+        assert_eq!(5, graph.node_count());
+        assert_eq!(3, graph.edge_count());
+        let (a, b) = (graph.id(&47).unwrap(), graph.id(&53).unwrap());
+        assert!(graph.has_edge(a, b) && !graph.has_edge(b, a));
+        assert_eq!(vec![&97, &5], graph.keys([2, 4]).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,70 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Dense id of an interned key, usable as an index.
+pub type NodeId = usize;
+
+/// Maps keys (e.g. node names) to dense ids and back.
+#[derive(Debug, Clone)]
+pub struct Interner<K> {
+    ids: HashMap<K, NodeId>,
+    keys: Vec<K>,
+}
+impl<K> Default for Interner<K> {
+    fn default() -> Self {
+        Self {
+            ids: HashMap::new(),
+            keys: Vec::new(),
+        }
+    }
+}
+impl<K: Hash + Eq + Clone> Interner<K> {
+    /// Returns the id of the key, assigning the next free one to new keys.
+    pub fn intern(&mut self, key: K) -> NodeId {
+        if let Some(id) = self.ids.get(&key) {
+            return *id;
+        }
+        let id = self.keys.len();
+        self.ids.insert(key.clone(), id);
+        self.keys.push(key);
+        id
+    }
+
+    #[must_use]
+    pub fn id(&self, key: &K) -> Option<NodeId> {
+        self.ids.get(key).copied()
+    }
+
+    /// # Panics
+    /// If the id wasn't handed out by this interner.
+    #[must_use]
+    pub fn key(&self, id: NodeId) -> &K {
+        &self.keys[id]
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intern() {
+        let mut interner = Interner::default();
+        assert_eq!(0, interner.intern("kh"));
+        assert_eq!(1, interner.intern("tc"));
+        assert_eq!(0, interner.intern("kh"));
+        assert_eq!(Some(1), interner.id(&"tc"));
+        assert_eq!(None, interner.id(&"qp"));
+        assert_eq!(&"tc", interner.key(1));
+        assert_eq!(2, interner.len());
+    }
+}
@@ -0,0 +1,11 @@
+mod cliques;
+mod components;
+mod graph;
+mod interner;
+mod order;
+mod precedence;
+
+pub use graph::*;
+pub use interner::*;
+pub use order::*;
+pub use precedence::*;
@@ -0,0 +1,202 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashSet},
+    fmt::{self, Display},
+    hash::Hash,
+};
+
+use crate::{Graph, NodeId};
+
+/// The ids of a cycle that prevents a topological order, in edge order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle(pub Vec<NodeId>);
+impl Display for Cycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Graph contains a cycle through the nodes {:?}", self.0)
+    }
+}
+impl std::error::Error for Cycle {}
+
+impl<K: Hash + Eq + Clone> Graph<K> {
+    /// Orders the nodes so that every edge points forward, ties are broken by the smaller id first.
+    ///
+    /// # Errors
+    /// A [`Cycle`] when the graph isn't acyclic.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn topological_sort(&self) -> Result<Vec<NodeId>, Cycle> {
+        let mut in_degree = vec![0; self.node_count()];
+        for node in self.nodes() {
+            for next in self.neighbours(node) {
+                in_degree[*next] += 1;
+            }
+        }
+        let mut ready: BinaryHeap<_> = self
+            .nodes()
+            .filter(|node| in_degree[*node] == 0)
+            .map(Reverse)
+            .collect();
+        let mut order = Vec::with_capacity(self.node_count());
+        while let Some(Reverse(node)) = ready.pop() {
+            order.push(node);
+            for next in self.neighbours(node) {
+                in_degree[*next] -= 1;
+                if in_degree[*next] == 0 {
+                    ready.push(Reverse(*next));
+                }
+            }
+        }
+        if order.len() == self.node_count() {
+            return Ok(order);
+        }
+
+        // every node left has a predecessor that's also left, walk them back until one repeats
+        let left: HashSet<_> = self.nodes().filter(|node| in_degree[*node] > 0).collect();
+        let mut predecessors = vec![Vec::new(); self.node_count()];
+        for node in &left {
+            for next in self.neighbours(*node) {
+                predecessors[*next].push(*node);
+            }
+        }
+        let mut path = vec![*left.iter().min().expect("Nodes left")];
+        let mut seen_at = vec![None; self.node_count()];
+        loop {
+            let node = *path.last().expect("Non-empty path");
+            if let Some(start) = seen_at[node] {
+                let mut cycle = path[start..path.len() - 1].to_vec();
+                cycle.reverse();
+                return Err(Cycle(cycle));
+            }
+            seen_at[node] = Some(path.len() - 1);
+            let prev = predecessors[node]
+                .iter()
+                .find(|prev| left.contains(prev))
+                .expect("Predecessor left");
+            path.push(*prev);
+        }
+    }
+
+    /// Strongly connected components (Tarjan), in reverse topological order of the condensed graph.
+    #[must_use]
+    pub fn strongly_connected_components(&self) -> Vec<Vec<NodeId>> {
+        let mut tarjan = Tarjan {
+            index: vec![None; self.node_count()],
+            low_link: vec![0; self.node_count()],
+            on_stack: vec![false; self.node_count()],
+            stack: Vec::new(),
+            next_index: 0,
+            components: Vec::new(),
+        };
+        for node in self.nodes() {
+            if tarjan.index[node].is_none() {
+                tarjan.visit(self, node);
+            }
+        }
+        tarjan.components
+    }
+}
+
+struct Tarjan {
+    index: Vec<Option<usize>>,
+    low_link: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<NodeId>,
+    next_index: usize,
+    components: Vec<Vec<NodeId>>,
+}
+impl Tarjan {
+    fn visit<K: Hash + Eq + Clone>(&mut self, graph: &Graph<K>, node: NodeId) {
+        self.index[node] = Some(self.next_index);
+        self.low_link[node] = self.next_index;
+        self.next_index += 1;
+        self.stack.push(node);
+        self.on_stack[node] = true;
+        for &next in graph.neighbours(node) {
+            match self.index[next] {
+                None => {
+                    self.visit(graph, next);
+                    self.low_link[node] = self.low_link[node].min(self.low_link[next]);
+                }
+                Some(index) if self.on_stack[next] => {
+                    self.low_link[node] = self.low_link[node].min(index);
+                }
+                Some(_) => {}
+            }
+        }
+        if Some(self.low_link[node]) == self.index[node] {
+            let mut component = Vec::new();
+            while let Some(member) = self.stack.pop() {
+                self.on_stack[member] = false;
+                component.push(member);
+                if member == node {
+                    break;
+                }
+            }
+            component.sort_unstable();
+            self.components.push(component);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the day-5 example rules
+    const RULES: [(u32, u32); 21] = [
+        (47, 53),
+        (97, 13),
+        (97, 61),
+        (97, 47),
+        (75, 29),
+        (61, 13),
+        (75, 53),
+        (29, 13),
+        (97, 29),
+        (53, 29),
+        (61, 53),
+        (97, 53),
+        (61, 29),
+        (47, 13),
+        (75, 47),
+        (97, 75),
+        (47, 61),
+        (75, 61),
+        (47, 29),
+        (75, 13),
+        (53, 13),
+    ];
+
+    #[test]
+    fn topological() {
+        let graph = Graph::from_directed_edges(RULES);
+        let order = graph.topological_sort().unwrap();
+        assert_eq!(
+            vec![&97, &75, &47, &61, &53, &29, &13],
+            graph.keys(order).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn cycle() {
+        let mut graph = Graph::from_directed_edges([(1, 2), (2, 3), (3, 4), (4, 2), (0, 1)]);
+        let Cycle(cycle) = graph.topological_sort().unwrap_err();
+        let mut cycle: Vec<_> = graph.keys(cycle).copied().collect();
+        let start = cycle.iter().position(|n| *n == 2).unwrap();
+        cycle.rotate_left(start);
+        assert_eq!(vec![2, 3, 4], cycle);
+        graph.add_edge(5, 5);
+        assert!(graph.topological_sort().is_err());
+    }
+
+    #[test]
+    fn scc() {
+        let graph =
+            Graph::from_directed_edges([(1, 2), (2, 3), (3, 1), (3, 4), (4, 5), (5, 4), (6, 5)]);
+        let components: Vec<Vec<_>> = graph
+            .strongly_connected_components()
+            .into_iter()
+            .map(|c| graph.keys(c).copied().collect())
+            .collect();
+        assert_eq!(vec![vec![4, 5], vec![1, 2, 3], vec![6]], components);
+    }
+}
@@ -0,0 +1,159 @@
+use std::{
+    collections::HashSet,
+    fmt::{self, Debug, Display},
+    hash::Hash,
+};
+
+use crate::{Cycle, Graph};
+
+/// Rules contradicting each other within a sequence, the elements form a cycle of "must precede".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contradiction<K>(pub Vec<K>);
+impl<K: Debug> Display for Contradiction<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Contradicting precedence rules between {:?}", self.0)
+    }
+}
+impl<K: Debug> std::error::Error for Contradiction<K> {}
+
+/// Pairwise `before|after` ordering rules.
+///
+/// The rules don't have to form a global order (they may be cyclic as a whole), only the ones
+/// between the elements of a checked or reordered sequence are considered.
+#[derive(Debug, Clone)]
+pub struct Precedence<K> {
+    rules: Graph<K>,
+}
+impl<K: Hash + Eq + Clone> Default for Precedence<K> {
+    fn default() -> Self {
+        Self {
+            rules: Graph::directed(),
+        }
+    }
+}
+impl<K: Hash + Eq + Clone> Precedence<K> {
+    pub fn add_rule(&mut self, before: K, after: K) {
+        self.rules.add_edge(before, after);
+    }
+
+    /// Whether a rule says `a` comes before `b`.
+    #[must_use]
+    pub fn precedes(&self, a: &K, b: &K) -> bool {
+        match (self.rules.id(a), self.rules.id(b)) {
+            (Some(a), Some(b)) => self.rules.has_edge(a, b),
+            _ => false,
+        }
+    }
+
+    /// Whether no element is preceded by one that a rule puts after it.
+    #[must_use]
+    pub fn is_ordered(&self, sequence: &[K]) -> bool {
+        sequence
+            .iter()
+            .enumerate()
+            .all(|(i, a)| sequence[i + 1..].iter().all(|b| !self.precedes(b, a)))
+    }
+
+    /// Sorts the (distinct) elements to satisfy the rules between them, unconstrained elements
+    /// keep their relative order.
+    ///
+    /// # Errors
+    /// A [`Contradiction`] if the rules between the elements are cyclic.
+    pub fn reorder(&self, sequence: &[K]) -> Result<Vec<K>, Contradiction<K>> {
+        // ids follow the sequence, so the topological order prefers the original order on ties
+        let mut subset = Graph::directed();
+        for element in sequence {
+            subset.add_node(element.clone());
+        }
+        let elements: HashSet<_> = sequence.iter().collect();
+        for before in sequence {
+            let Some(id) = self.rules.id(before) else {
+                continue;
+            };
+            let afters = self
+                .rules
+                .neighbours(id)
+                .iter()
+                .map(|id| self.rules.key(*id));
+            for after in afters.filter(|after| elements.contains(after)) {
+                subset.add_edge(before.clone(), after.clone());
+            }
+        }
+        match subset.topological_sort() {
+            Ok(order) => Ok(subset.keys(order).cloned().collect()),
+            Err(Cycle(cycle)) => Err(Contradiction(subset.keys(cycle).cloned().collect())),
+        }
+    }
+}
+impl<K: Hash + Eq + Clone> FromIterator<(K, K)> for Precedence<K> {
+    fn from_iter<T: IntoIterator<Item = (K, K)>>(rules: T) -> Self {
+        let mut precedence = Self::default();
+        precedence.extend(rules);
+        precedence
+    }
+}
+impl<K: Hash + Eq + Clone> Extend<(K, K)> for Precedence<K> {
+    fn extend<T: IntoIterator<Item = (K, K)>>(&mut self, rules: T) {
+        for (before, after) in rules {
+            self.add_rule(before, after);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    // the day-5 example rules
+    const RULES: &str = "47|53 97|13 97|61 97|47 75|29 61|13 75|53 29|13 97|29 53|29 61|53 \
+        97|53 61|29 47|13 75|47 97|75 47|61 75|61 47|29 75|13 53|13";
+
+    fn rules() -> Precedence<u32> {
+        RULES
+            .split_whitespace()
+            .map(|rule| {
+                let (a, b) = rule.split_once('|').unwrap();
+                (a.parse().unwrap(), b.parse().unwrap())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn precedes() {
+        let rules = rules();
+        assert!(rules.precedes(&47, &53));
+        assert!(!rules.precedes(&53, &47));
+        assert!(!rules.precedes(&1, &47));
+    }
+
+    #[test_case(&[75, 47, 61, 53, 29] => true)]
+    #[test_case(&[97, 61, 53, 29, 13] => true)]
+    #[test_case(&[75, 29, 13] => true)]
+    #[test_case(&[75, 97, 47, 61, 53] => false)]
+    #[test_case(&[61, 13, 29] => false)]
+    #[test_case(&[97, 13, 75, 29, 47] => false)]
+    fn ordered(sequence: &[u32]) -> bool {
+        rules().is_ordered(sequence)
+    }
+
+    #[test_case(&[75, 97, 47, 61, 53] => vec![97, 75, 47, 61, 53])]
+    #[test_case(&[61, 13, 29] => vec![61, 29, 13])]
+    #[test_case(&[97, 13, 75, 29, 47] => vec![97, 75, 47, 29, 13])]
+    #[test_case(&[5, 13, 4, 61] => vec![5, 4, 61, 13])]
+    fn reorder(sequence: &[u32]) -> Vec<u32> {
+        let res = rules().reorder(sequence).unwrap();
+        assert!(rules().is_ordered(&res));
+        res
+    }
+
+    #[test]
+    fn contradiction() {
+        let mut rules = rules();
+        rules.add_rule(13, 97);
+        // the cycle only matters when all of its elements are in the sequence
+        assert!(rules.reorder(&[97, 75, 29]).is_ok());
+        let Contradiction(cycle) = rules.reorder(&[13, 97, 75]).unwrap_err();
+        assert!(cycle.contains(&13) && cycle.contains(&97));
+    }
+}
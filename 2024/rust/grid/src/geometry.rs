@@ -0,0 +1,272 @@
+//! Lattice polygon and tile region helpers.
+//!
+//! Polygons are closed vertex loops (the last vertex connects back to the first). Tile regions
+//! are sets of tiles where the tile `(x, y)` covers the square between the corners `(x, y)` and
+//! `(x + 1, y + 1)`.
+
+use std::collections::HashSet;
+
+use glam::IVec2;
+
+use crate::dir::DIRS_4;
+
+/// Twice the signed area (shoelace formula), positive for clockwise loops in screen coordinates (y down).
+#[must_use]
+pub fn signed_double_area(vertices: &[IVec2]) -> i64 {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(a, b)| i64::from(a.x) * i64::from(b.y) - i64::from(b.x) * i64::from(a.y))
+        .sum()
+}
+
+/// Twice the area, it's always an integer for lattice polygons.
+#[must_use]
+pub fn double_area(vertices: &[IVec2]) -> i64 {
+    signed_double_area(vertices).abs()
+}
+
+/// Number of lattice points on the polygon boundary.
+#[must_use]
+pub fn boundary_points(vertices: &[IVec2]) -> i64 {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(a, b)| {
+            let delta = (*b - *a).abs();
+            math::gcd(i64::from(delta.x), i64::from(delta.y))
+        })
+        .sum()
+}
+
+/// Number of lattice points strictly inside the polygon (Pick's theorem).
+#[must_use]
+pub fn interior_points(vertices: &[IVec2]) -> i64 {
+    (double_area(vertices) - boundary_points(vertices)) / 2 + 1
+}
+
+/// Traces the region outline into polygons of tile corners, the outer boundary and any holes.
+///
+/// Collinear vertices are dropped so each vertex is a corner and each polygon edge a whole side.
+/// The region is on the right of every edge, the outer loops are clockwise (positive
+/// [`signed_double_area`]) and the holes counter-clockwise. Tiles touching only diagonally
+/// get separate loops.
+#[must_use]
+#[allow(clippy::implicit_hasher)]
+pub fn trace_region(tiles: &HashSet<IVec2>) -> Vec<Vec<IVec2>> {
+    // unit edges keyed by their start corner and direction
+    let mut edges: HashSet<(IVec2, IVec2)> = HashSet::new();
+    for tile in tiles {
+        for dir in DIRS_4 {
+            if tiles.contains(&(tile + dir)) {
+                continue;
+            }
+            // the side facing `dir`, walked with the tile on the right
+            let edge_dir = dir.perp();
+            let start = *tile + corner_offset(dir);
+            edges.insert((start, edge_dir));
+        }
+    }
+    let mut polygons = Vec::new();
+    while let Some(&first) = edges.iter().next() {
+        let mut vertices = Vec::new();
+        let (mut pos, mut dir) = first;
+        loop {
+            edges.remove(&(pos, dir));
+            let next_pos = pos + dir;
+            // at pinch corners prefer turning right (towards the region) to keep the diagonal tiles apart
+            let next_dir = [dir.perp(), dir, -dir.perp()]
+                .into_iter()
+                .find(|d| edges.contains(&(next_pos, *d)) || (next_pos, *d) == first);
+            let Some(next_dir) = next_dir else {
+                break;
+            };
+            if next_dir != dir {
+                vertices.push(next_pos);
+            }
+            (pos, dir) = (next_pos, next_dir);
+            if (pos, dir) == first {
+                break;
+            }
+        }
+        polygons.push(vertices);
+    }
+    polygons
+}
+
+/// The start corner of the tile side facing `dir` when walking it clockwise.
+fn corner_offset(dir: IVec2) -> IVec2 {
+    match dir {
+        IVec2::NEG_Y => IVec2::ZERO,
+        IVec2::X => IVec2::X,
+        IVec2::Y => IVec2::ONE,
+        _ => IVec2::Y,
+    }
+}
+
+/// Number of corners of the region outline, which equals its number of sides.
+#[must_use]
+#[allow(clippy::implicit_hasher)]
+pub fn count_corners(tiles: &HashSet<IVec2>) -> usize {
+    tiles
+        .iter()
+        .map(|tile| {
+            DIRS_4
+                .iter()
+                .zip(DIRS_4.iter().cycle().skip(1))
+                .filter(|(a, b)| {
+                    let (has_a, has_b) =
+                        (tiles.contains(&(tile + *a)), tiles.contains(&(tile + *b)));
+                    let convex = !has_a && !has_b;
+                    let concave = has_a && has_b && !tiles.contains(&(tile + *a + *b));
+                    convex || concave
+                })
+                .count()
+        })
+        .sum()
+}
+
+/// Number of unit sides on the region outline.
+#[must_use]
+#[allow(clippy::implicit_hasher)]
+pub fn perimeter(tiles: &HashSet<IVec2>) -> usize {
+    tiles
+        .iter()
+        .map(|tile| {
+            DIRS_4
+                .iter()
+                .filter(|dir| !tiles.contains(&(tile + **dir)))
+                .count()
+        })
+        .sum()
+}
+
+/// Convex hull without collinear points (monotone chain), clockwise in screen coordinates (y down)
+/// starting at the smallest `(x, y)` point.
+#[must_use]
+pub fn convex_hull(points: &[IVec2]) -> Vec<IVec2> {
+    let mut sorted = points.to_vec();
+    sorted.sort_unstable_by_key(|p| (p.x, p.y));
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+    let cross = |o: IVec2, a: IVec2, b: IVec2| {
+        let (a, b) = ((a - o).as_i64vec2(), (b - o).as_i64vec2());
+        a.x * b.y - a.y * b.x
+    };
+    let chain = |points: &mut dyn Iterator<Item = &IVec2>| {
+        let mut chain: Vec<IVec2> = Vec::new();
+        for &p in points {
+            while chain.len() >= 2 && cross(chain[chain.len() - 2], chain[chain.len() - 1], p) <= 0
+            {
+                chain.pop();
+            }
+            chain.push(p);
+        }
+        // the last point starts the other chain
+        chain.pop();
+        chain
+    };
+    let mut hull = chain(&mut sorted.iter());
+    hull.extend(chain(&mut sorted.iter().rev()));
+    hull
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    const EXAMPLE: &str = "AAAA\nBBCD\nBBCC\nEEEC";
+    const E_MAP: &str = "EEEEE\nEXXXX\nEEEEE\nEXXXX\nEEEEE";
+    const HOLES: &str = "OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO";
+    const PINCHED: &str = "AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA";
+
+    fn tiles(map: &str, plant: char) -> HashSet<IVec2> {
+        map.lines()
+            .enumerate()
+            .flat_map(|(y, l)| {
+                l.chars()
+                    .enumerate()
+                    .filter(move |(_, c)| *c == plant)
+                    .map(move |(x, _)| IVec2::new(x as _, y as _))
+            })
+            .collect()
+    }
+
+    #[test_case(EXAMPLE, 'A' => (4, 10, 4))]
+    #[test_case(EXAMPLE, 'C' => (4, 10, 8))]
+    #[test_case(EXAMPLE, 'D' => (1, 4, 4))]
+    #[test_case(E_MAP, 'E' => (17, 36, 12))]
+    #[test_case(HOLES, 'O' => (21, 36, 20))]
+    #[test_case(PINCHED, 'A' => (28, 40, 12))]
+    fn region(map: &str, plant: char) -> (usize, usize, usize) {
+        let region = tiles(map, plant);
+        let polygons = trace_region(&region);
+        let corners = count_corners(&region);
+        assert_eq!(corners, polygons.iter().map(Vec::len).sum());
+        let area: i64 = polygons.iter().map(|p| signed_double_area(p)).sum();
+        assert_eq!(region.len() as i64 * 2, area);
+        (region.len(), perimeter(&region), corners)
+    }
+
+    #[test]
+    fn trace_holes() {
+        let polygons = trace_region(&tiles(HOLES, 'O'));
+        assert_eq!(5, polygons.len());
+        let outer = polygons.iter().find(|p| signed_double_area(p) > 0).unwrap();
+        assert_eq!(50, double_area(outer));
+        // the pinched B blocks of the other map get two separate loops
+        assert_eq!(2, trace_region(&tiles(PINCHED, 'B')).len());
+    }
+
+    #[test]
+    fn pick() {
+        let square = [
+            IVec2::ZERO,
+            IVec2::new(6, 0),
+            IVec2::new(6, 6),
+            IVec2::new(0, 6),
+        ];
+        assert_eq!(72, double_area(&square));
+        assert_eq!(24, boundary_points(&square));
+        assert_eq!(25, interior_points(&square));
+        let triangle = [IVec2::ZERO, IVec2::new(4, 0), IVec2::new(0, 3)];
+        assert_eq!(12, double_area(&triangle));
+        assert_eq!(8, boundary_points(&triangle));
+        assert_eq!(3, interior_points(&triangle));
+    }
+
+    #[test]
+    fn hull() {
+        let points = [
+            (0, 0),
+            (2, 0),
+            (4, 0),
+            (1, 1),
+            (2, 2),
+            (4, 4),
+            (0, 4),
+            (3, 1),
+            (0, 2),
+            (2, 0),
+        ]
+        .map(IVec2::from);
+        assert_eq!(
+            vec![
+                IVec2::ZERO,
+                IVec2::new(4, 0),
+                IVec2::new(4, 4),
+                IVec2::new(0, 4)
+            ],
+            convex_hull(&points)
+        );
+        assert!(signed_double_area(&convex_hull(&points)) > 0);
+        assert_eq!(2, convex_hull(&[IVec2::ONE, IVec2::ZERO, IVec2::ONE]).len());
+        assert_eq!(
+            vec![IVec2::ZERO, IVec2::new(3, 3)],
+            convex_hull(&[IVec2::ZERO, IVec2::ONE, IVec2::new(3, 3)])
+        );
+    }
+}
@@ -1,5 +1,6 @@
 pub mod dir;
 mod ext;
+pub mod geometry;
 mod grid;
 pub mod iter;
 
@@ -11,6 +12,7 @@ pub mod prelude {
 
     pub use crate::dir::*;
     pub use crate::ext::*;
+    pub use crate::geometry;
     pub use crate::grid::builder::*;
     pub use crate::grid::*;
     pub use crate::iter::*;
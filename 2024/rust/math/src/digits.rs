@@ -0,0 +1,258 @@
+//! Decimal (or any base) digit manipulation of unsigned integers.
+
+use crate::POWERS_OF_10;
+
+pub const POWERS_OF_10_U128: [u128; 39] = {
+    let mut powers = [1; 39];
+    let mut i = 1;
+    while i < powers.len() {
+        powers[i] = powers[i - 1] * 10;
+        i += 1;
+    }
+    powers
+};
+
+pub trait Digits: Sized + Copy {
+    /// Number of decimal digits, `0` has a single digit.
+    #[must_use]
+    fn digit_count(self) -> u32;
+    /// Number of digits in the `base`, `0` has a single digit.
+    #[must_use]
+    fn digit_count_in(self, base: u32) -> u32;
+    /// `10^exp`, `None` if it doesn't fit the type.
+    fn checked_pow10(exp: u32) -> Option<Self>;
+    /// Appends the decimal digits of `other`, e.g. `12.concat(345) == 12345`.
+    ///
+    /// # Panics
+    /// On overflow, see [`Digits::checked_concat`].
+    #[must_use]
+    fn concat(self, other: Self) -> Self {
+        self.checked_concat(other)
+            .expect("Concatenated number overflow")
+    }
+    fn checked_concat(self, other: Self) -> Option<Self>;
+    /// Splits off the lowest `low_digit_count` decimal digits, e.g. `12345.split_at_digit(2) == (123, 45)`.
+    #[must_use]
+    fn split_at_digit(self, low_digit_count: u32) -> (Self, Self);
+    /// Splits a number with an even digit count into its halves, e.g. `1234 -> (12, 34)`.
+    #[must_use]
+    fn split_half(self) -> Option<(Self, Self)> {
+        let count = self.digit_count();
+        count
+            .is_multiple_of(2)
+            .then(|| self.split_at_digit(count / 2))
+    }
+    /// Iterates over the digits in the `base` from the most significant one.
+    #[must_use]
+    fn digits(self, base: u32) -> DigitIter<Self>;
+    /// Iterates over the digits in the `base` from the least significant one.
+    #[must_use]
+    fn digits_rev(self, base: u32) -> RevDigitIter<Self>;
+    /// Reverses the decimal digits, e.g. `1230 -> 321`.
+    ///
+    /// # Panics
+    /// On overflow, see [`Digits::checked_reverse_digits`].
+    #[must_use]
+    fn reverse_digits(self) -> Self {
+        self.checked_reverse_digits()
+            .expect("Reversed number overflow")
+    }
+    fn checked_reverse_digits(self) -> Option<Self>;
+}
+
+/// Most significant first digit iterator, see [`Digits::digits`].
+pub struct DigitIter<T> {
+    num: T,
+    divisor: T,
+    base: T,
+}
+
+/// Least significant first digit iterator, see [`Digits::digits_rev`].
+pub struct RevDigitIter<T> {
+    num: Option<T>,
+    base: T,
+}
+
+macro_rules! impl_digits {
+    ($($int: ty => $powers: ident),*) => {
+        $(
+            impl Digits for $int {
+                fn digit_count(self) -> u32 {
+                    self.checked_ilog10().unwrap_or_default() + 1
+                }
+
+                fn digit_count_in(self, base: u32) -> u32 {
+                    self.checked_ilog(<$int>::from(base)).unwrap_or_default() + 1
+                }
+
+                fn checked_pow10(exp: u32) -> Option<Self> {
+                    $powers.get(exp as usize).copied()
+                }
+
+                fn checked_concat(self, other: Self) -> Option<Self> {
+                    Self::checked_pow10(other.digit_count())?
+                        .checked_mul(self)?
+                        .checked_add(other)
+                }
+
+                fn split_at_digit(self, low_digit_count: u32) -> (Self, Self) {
+                    match Self::checked_pow10(low_digit_count) {
+                        Some(divisor) => (self / divisor, self % divisor),
+                        None => (0, self),
+                    }
+                }
+
+                fn digits(self, base: u32) -> DigitIter<Self> {
+                    assert!(base > 1, "Digit base has to be at least 2");
+                    let base = <$int>::from(base);
+                    DigitIter {
+                        num: self,
+                        divisor: base.pow(self.digit_count_in(base as _) - 1),
+                        base,
+                    }
+                }
+
+                fn digits_rev(self, base: u32) -> RevDigitIter<Self> {
+                    assert!(base > 1, "Digit base has to be at least 2");
+                    RevDigitIter {
+                        num: Some(self),
+                        base: <$int>::from(base),
+                    }
+                }
+
+                fn checked_reverse_digits(self) -> Option<Self> {
+                    self.digits_rev(10)
+                        .try_fold(0 as $int, |acc, digit| {
+                            acc.checked_mul(10)?.checked_add(<$int>::from(digit))
+                        })
+                }
+            }
+
+            impl Iterator for DigitIter<$int> {
+                type Item = u32;
+
+                fn next(&mut self) -> Option<Self::Item> {
+                    if self.divisor == 0 {
+                        return None;
+                    }
+                    let digit = self.num / self.divisor;
+                    self.num %= self.divisor;
+                    self.divisor /= self.base;
+                    Some(digit as _)
+                }
+            }
+
+            impl Iterator for RevDigitIter<$int> {
+                type Item = u32;
+
+                fn next(&mut self) -> Option<Self::Item> {
+                    let num = self.num?;
+                    let next = num / self.base;
+                    self.num = (next > 0).then_some(next);
+                    Some((num % self.base) as _)
+                }
+            }
+        )*
+    };
+}
+
+impl_digits!(u64 => POWERS_OF_10, u128 => POWERS_OF_10_U128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(0 => 1)]
+    #[test_case(9 => 1)]
+    #[test_case(10 => 2)]
+    #[test_case(2024 => 4)]
+    #[test_case(u64::MAX => 20)]
+    fn count_u64(num: u64) -> u32 {
+        num.digit_count()
+    }
+
+    #[test]
+    fn count_u128() {
+        assert_eq!(39, u128::MAX.digit_count());
+        assert_eq!(128, u128::MAX.digit_count_in(2));
+        assert_eq!(2, 255u128.digit_count_in(16));
+    }
+
+    #[test]
+    fn powers() {
+        assert!(POWERS_OF_10
+            .iter()
+            .zip(POWERS_OF_10_U128)
+            .all(|(a, b)| u128::from(*a) == b));
+        assert_eq!(None, u64::checked_pow10(20));
+        assert_eq!(Some(10u128.pow(38)), u128::checked_pow10(38));
+    }
+
+    #[test_case(12, 345 => 12_345)]
+    #[test_case(6, 0 => 60)]
+    #[test_case(0, 7 => 7)]
+    #[test_case(15, 6 => 156)]
+    fn concat_u64(a: u64, b: u64) -> u64 {
+        a.concat(b)
+    }
+
+    #[test]
+    fn concat_overflow() {
+        assert_eq!(None, u64::MAX.checked_concat(1));
+        assert_eq!(None, u128::MAX.checked_concat(0));
+        assert_eq!(None, 2u64.checked_concat(u64::MAX));
+        assert_eq!(
+            Some(1_844_674_407_370_955_161),
+            184_467u64.checked_concat(4_407_370_955_161)
+        );
+        assert_eq!(
+            Some(1_234_518_446_744_073_709_551_615),
+            12_345u128.checked_concat(u128::from(u64::MAX))
+        );
+    }
+
+    #[test_case(12_345, 2 => (123, 45))]
+    #[test_case(12_345, 0 => (12_345, 0))]
+    #[test_case(12_345, 5 => (0, 12_345))]
+    #[test_case(1000, 2 => (10, 0))]
+    #[test_case(12_345, 25 => (0, 12_345))]
+    fn split_u64(num: u64, low_digits: u32) -> (u64, u64) {
+        num.split_at_digit(low_digits)
+    }
+
+    #[test_case(2024 => Some((20, 24)))]
+    #[test_case(1000 => Some((10, 0)))]
+    #[test_case(99 => Some((9, 9)))]
+    #[test_case(123 => None)]
+    #[test_case(0 => None)]
+    fn half_u64(num: u64) -> Option<(u64, u64)> {
+        num.split_half()
+    }
+
+    #[test_case(1230, 10 => vec![1, 2, 3, 0])]
+    #[test_case(0, 10 => vec![0])]
+    #[test_case(0b1011, 2 => vec![1, 0, 1, 1])]
+    #[test_case(0xff0, 16 => vec![15, 15, 0])]
+    #[test_case(u64::MAX, 10 => vec![1, 8, 4, 4, 6, 7, 4, 4, 0, 7, 3, 7, 0, 9, 5, 5, 1, 6, 1, 5])]
+    fn digit_iter(num: u64, base: u32) -> Vec<u32> {
+        let digits: Vec<_> = num.digits(base).collect();
+        let mut rev: Vec<_> = num.digits_rev(base).collect();
+        rev.reverse();
+        assert_eq!(digits, rev);
+        digits
+    }
+
+    #[test]
+    fn digit_iter_u128() {
+        assert_eq!(128, u128::MAX.digits(2).filter(|d| *d == 1).count());
+    }
+
+    #[test_case(1230 => Some(321))]
+    #[test_case(7 => Some(7))]
+    #[test_case(1_000_000_000_000_000_009 => Some(9_000_000_000_000_000_001))]
+    #[test_case(u64::MAX => None)]
+    fn reverse_u64(num: u64) -> Option<u64> {
+        num.checked_reverse_digits()
+    }
+}
@@ -0,0 +1,155 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+/// Primitive integers the number theory helpers are generic over.
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MAX: Self;
+
+    #[must_use]
+    fn abs(self) -> Self;
+    #[must_use]
+    fn rem_euclid(self, rhs: Self) -> Self;
+    #[must_use]
+    fn isqrt(self) -> Self;
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    /// `self * rhs mod modulus` without overflowing the intermediate product.
+    #[must_use]
+    fn mul_mod(self, rhs: Self, modulus: Self) -> Self;
+}
+
+/// Marker for signed integers (required for Bézout coefficients).
+pub trait SignedInteger: Integer + Neg<Output = Self> {}
+
+macro_rules! impl_integer_common {
+    () => {
+        const ZERO: Self = 0;
+        const ONE: Self = 1;
+        const MAX: Self = Self::MAX;
+
+        fn rem_euclid(self, rhs: Self) -> Self {
+            self.rem_euclid(rhs)
+        }
+
+        fn isqrt(self) -> Self {
+            self.isqrt()
+        }
+
+        fn checked_add(self, rhs: Self) -> Option<Self> {
+            self.checked_add(rhs)
+        }
+
+        fn checked_mul(self, rhs: Self) -> Option<Self> {
+            self.checked_mul(rhs)
+        }
+    };
+}
+
+macro_rules! impl_integer {
+    (unsigned: $($int: ty => $wide: ty),*) => {
+        $(
+            impl Integer for $int {
+                impl_integer_common!();
+
+                fn abs(self) -> Self {
+                    self
+                }
+
+                fn mul_mod(self, rhs: Self, modulus: Self) -> Self {
+                    (<$wide>::from(self) * <$wide>::from(rhs) % <$wide>::from(modulus)) as _
+                }
+            }
+        )*
+    };
+    (signed: $($int: ty => $wide: ty),*) => {
+        $(
+            impl Integer for $int {
+                impl_integer_common!();
+
+                fn abs(self) -> Self {
+                    self.abs()
+                }
+
+                fn mul_mod(self, rhs: Self, modulus: Self) -> Self {
+                    (<$wide>::from(self) * <$wide>::from(rhs)).rem_euclid(<$wide>::from(modulus)) as _
+                }
+            }
+
+            impl SignedInteger for $int {}
+        )*
+    };
+    (unsigned_wide: $($int: ty),*) => {
+        $(
+            impl Integer for $int {
+                impl_integer_common!();
+
+                fn abs(self) -> Self {
+                    self
+                }
+
+                fn mul_mod(self, rhs: Self, modulus: Self) -> Self {
+                    mul_mod_wide(self % modulus, rhs % modulus, modulus)
+                }
+            }
+        )*
+    };
+    (signed_wide: $($int: ty),*) => {
+        $(
+            impl Integer for $int {
+                impl_integer_common!();
+
+                fn abs(self) -> Self {
+                    self.abs()
+                }
+
+                fn mul_mod(self, rhs: Self, modulus: Self) -> Self {
+                    mul_mod_wide(self.rem_euclid(modulus), rhs.rem_euclid(modulus), modulus)
+                }
+            }
+
+            impl SignedInteger for $int {}
+        )*
+    };
+}
+
+impl_integer!(unsigned: u8 => u16, u16 => u32, u32 => u64, u64 => u128);
+impl_integer!(signed: i8 => i16, i16 => i32, i32 => i64, i64 => i128);
+impl_integer!(unsigned_wide: u128, usize);
+impl_integer!(signed_wide: i128, isize);
+
+// double-and-add for types without a wider primitive, expects `0 <= a, b < modulus`
+fn mul_mod_wide<T: Integer>(mut a: T, mut b: T, modulus: T) -> T {
+    let two = T::ONE + T::ONE;
+    let mut res = T::ZERO;
+    while b > T::ZERO {
+        if b % two == T::ONE {
+            res = add_mod(res, a, modulus);
+        }
+        a = add_mod(a, a, modulus);
+        b = b / two;
+    }
+    res
+}
+
+fn add_mod<T: Integer>(a: T, b: T, modulus: T) -> T {
+    // a + b could overflow, but a - (modulus - b) can't as both are < modulus
+    if a >= modulus - b {
+        a - (modulus - b)
+    } else {
+        a + b
+    }
+}
@@ -1,3 +1,17 @@
+pub mod digits;
+mod int;
+pub mod linalg;
+mod number_theory;
+mod operator_search;
+mod rational;
+mod windows;
+
+pub use int::*;
+pub use number_theory::*;
+pub use operator_search::*;
+pub use rational::*;
+pub use windows::*;
+
 pub const POWERS_OF_10: [u64; 20] = [
     1,
     10,
@@ -0,0 +1,196 @@
+//! Exact solving of square linear systems with fraction-free (Bareiss) elimination.
+//!
+//! All intermediate values are minors of the input matrix, so the divisions are exact and
+//! the integer type only has to hold the products of two such minors.
+
+use crate::{Rational, SignedInteger};
+
+/// Outcome of [`solve`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Solution<T, const N: usize> {
+    /// The unique solution has only integer components.
+    Integer([T; N]),
+    /// The unique solution has a fractional component.
+    Rational([Rational<T>; N]),
+    /// The matrix is singular and the equations are consistent.
+    Infinite,
+    /// The matrix is singular and the equations contradict each other.
+    Inconsistent,
+}
+impl<T, const N: usize> Solution<T, N> {
+    /// The unique integer solution, if there is one.
+    pub fn integer(self) -> Option<[T; N]> {
+        match self {
+            Self::Integer(solution) => Some(solution),
+            _ => None,
+        }
+    }
+}
+impl<T: SignedInteger, const N: usize> Solution<T, N> {
+    /// The unique solution (integer or not), if there is one.
+    pub fn rational(self) -> Option<[Rational<T>; N]> {
+        match self {
+            Self::Integer(solution) => Some(solution.map(Rational::from_integer)),
+            Self::Rational(solution) => Some(solution),
+            _ => None,
+        }
+    }
+}
+
+/// Fraction-free elimination over the first `cols` columns, returns the rank.
+///
+/// Rows below each pivot are always eliminated, rows above only with `jordan` (which must only
+/// be used for non-singular matrices). The last pivot (`±det` for a full rank square part) ends up
+/// on the diagonal of every pivot row.
+fn eliminate<T: SignedInteger>(rows: &mut [Vec<T>], cols: usize, jordan: bool) -> (usize, bool) {
+    let mut prev_pivot = T::ONE;
+    let mut rank = 0;
+    let mut odd_swaps = false;
+    for col in 0..cols {
+        let Some(pivot_row) = (rank..rows.len()).find(|&i| rows[i][col] != T::ZERO) else {
+            continue;
+        };
+        if pivot_row != rank {
+            rows.swap(rank, pivot_row);
+            odd_swaps = !odd_swaps;
+        }
+        let pivot_values = rows[rank].clone();
+        let pivot = pivot_values[col];
+        let start = if jordan { 0 } else { rank + 1 };
+        for (i, row) in rows.iter_mut().enumerate().skip(start) {
+            if i == rank {
+                continue;
+            }
+            let factor = row[col];
+            for (val, pivot_val) in row.iter_mut().zip(&pivot_values) {
+                *val = (pivot * *val - factor * *pivot_val) / prev_pivot;
+            }
+        }
+        prev_pivot = pivot;
+        rank += 1;
+    }
+    (rank, odd_swaps)
+}
+
+/// Determinant of the square matrix.
+#[must_use]
+pub fn determinant<T: SignedInteger, const N: usize>(matrix: [[T; N]; N]) -> T {
+    if N == 0 {
+        return T::ONE;
+    }
+    let mut rows: Vec<_> = matrix.iter().map(|row| row.to_vec()).collect();
+    let (rank, odd_swaps) = eliminate(&mut rows, N, false);
+    if rank < N {
+        T::ZERO
+    } else if odd_swaps {
+        -rows[N - 1][N - 1]
+    } else {
+        rows[N - 1][N - 1]
+    }
+}
+
+/// Solves `matrix * x = rhs` exactly.
+#[must_use]
+pub fn solve<T: SignedInteger, const N: usize>(matrix: [[T; N]; N], rhs: [T; N]) -> Solution<T, N> {
+    let augmented = || -> Vec<Vec<T>> {
+        matrix
+            .iter()
+            .zip(rhs)
+            .map(|(row, val)| row.iter().copied().chain([val]).collect())
+            .collect()
+    };
+    let mut rows = augmented();
+    let (rank, _) = eliminate(&mut rows, N, false);
+    if rank < N {
+        return if rows[rank..].iter().all(|row| row[N] == T::ZERO) {
+            Solution::Infinite
+        } else {
+            Solution::Inconsistent
+        };
+    }
+
+    let mut rows = augmented();
+    eliminate(&mut rows, N, true);
+    // every row is now `det * x_i = rows[i][N]` (up to the sign of the determinant)
+    let denominator = rows.last().map_or(T::ONE, |row| row[N - 1]);
+    let solution: [_; N] = std::array::from_fn(|i| Rational::new(rows[i][N], denominator));
+    if solution.iter().all(Rational::is_integer) {
+        Solution::Integer(solution.map(|val| val.numer()))
+    } else {
+        Solution::Rational(solution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case([[3]] => 3)]
+    #[test_case([[1, 2], [3, 4]] => -2)]
+    #[test_case([[0, 1], [1, 0]] => -1)]
+    #[test_case([[1, 2], [2, 4]] => 0)]
+    #[test_case([[2, -3, 1], [2, 0, -1], [1, 4, 5]] => 49)]
+    #[test_case([[0, 0, 1], [0, 1, 0], [1, 0, 0]] => -1)]
+    #[test_case([[1, 2, 3], [4, 5, 6], [7, 8, 9]] => 0)]
+    fn det<const N: usize>(matrix: [[i64; N]; N]) -> i64 {
+        determinant(matrix)
+    }
+
+    #[test]
+    fn det_degenerate() {
+        assert_eq!(1, determinant::<i32, 0>([]));
+        assert_eq!(0, determinant([[0, 0], [0, 0]]));
+    }
+
+    #[test]
+    fn solve_empty() {
+        assert_eq!(Solution::Integer([]), solve::<i64, 0>([], []));
+    }
+
+    #[test]
+    fn claw_machines() {
+        // buttons as columns, the prize as the right hand side
+        assert_eq!(
+            Solution::Integer([80, 40]),
+            solve([[94, 22], [34, 67]], [8400, 5400])
+        );
+        assert_eq!(None, solve([[26, 67], [66, 21]], [12748, 12176]).integer());
+        let far = 10_000_000_000_000i64;
+        assert_eq!(
+            Solution::Integer([118_679_050_709, 103_199_174_542]),
+            solve([[26, 67], [66, 21]], [12748 + far, 12176 + far])
+        );
+    }
+
+    #[test_case([[2, 1], [1, 3]], [1, 2] => Solution::Rational([Rational::new(1, 5), Rational::new(3, 5)]))]
+    #[test_case([[0, 2], [-3, 0]], [1, 1] => Solution::Rational([Rational::new(-1, 3), Rational::new(1, 2)]))]
+    #[test_case([[1, 1], [2, 2]], [3, 6] => Solution::Infinite)]
+    #[test_case([[1, 1], [2, 2]], [3, 7] => Solution::Inconsistent)]
+    #[test_case([[0, 0], [0, 0]], [0, 0] => Solution::Infinite)]
+    #[test_case([[0, 0], [0, 0]], [0, 1] => Solution::Inconsistent)]
+    fn special_cases(matrix: [[i64; 2]; 2], rhs: [i64; 2]) -> Solution<i64, 2> {
+        solve(matrix, rhs)
+    }
+
+    #[test]
+    fn matches_substitution() {
+        // pseudo random systems with a known integer solution, checked by multiplying back
+        let mut seed = 7i64;
+        let mut next = || {
+            seed = (seed * 1_103_515_245 + 12345).rem_euclid(1 << 31);
+            seed % 41 - 20
+        };
+        for _ in 0..200 {
+            let matrix: [[i128; 4]; 4] =
+                std::array::from_fn(|_| std::array::from_fn(|_| next().into()));
+            let x: [i128; 4] = std::array::from_fn(|_| next().into());
+            let rhs = matrix.map(|row| row.iter().zip(x).map(|(a, b)| a * b).sum());
+            match solve(matrix, rhs) {
+                Solution::Integer(res) => assert_eq!(x, res),
+                Solution::Infinite => assert_eq!(0, determinant(matrix)),
+                res => panic!("unexpected {res:?} for {matrix:?}"),
+            }
+        }
+    }
+}
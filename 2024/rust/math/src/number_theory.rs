@@ -0,0 +1,255 @@
+use crate::{Integer, SignedInteger};
+
+/// Greatest common divisor, always non-negative (`gcd(0, 0) == 0`).
+#[must_use]
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, always non-negative (`lcm(0, x) == 0`).
+#[must_use]
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    (a / gcd(a, b) * b).abs()
+}
+
+/// Returns `(g, x, y)` where `g = gcd(a, b)` and `a * x + b * y = g`.
+#[must_use]
+pub fn extended_gcd<T: SignedInteger>(a: T, b: T) -> (T, T, T) {
+    let (mut old_rem, mut rem) = (a, b);
+    let (mut old_coef_a, mut coef_a) = (T::ONE, T::ZERO);
+    let (mut old_coef_b, mut coef_b) = (T::ZERO, T::ONE);
+    while rem != T::ZERO {
+        let quotient = old_rem / rem;
+        (old_rem, rem) = (rem, old_rem - quotient * rem);
+        (old_coef_a, coef_a) = (coef_a, old_coef_a - quotient * coef_a);
+        (old_coef_b, coef_b) = (coef_b, old_coef_b - quotient * coef_b);
+    }
+    if old_rem < T::ZERO {
+        (-old_rem, -old_coef_a, -old_coef_b)
+    } else {
+        (old_rem, old_coef_a, old_coef_b)
+    }
+}
+
+/// Modular inverse of `a` in `[0, modulus)`, `None` if `a` and `modulus` aren't coprime
+/// (or `modulus` is zero).
+#[must_use]
+pub fn mod_inv<T: SignedInteger>(a: T, modulus: T) -> Option<T> {
+    if modulus == T::ZERO {
+        return None;
+    }
+    let modulus = modulus.abs();
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == T::ONE).then(|| x.rem_euclid(modulus))
+}
+
+/// `base^exp mod modulus` by squaring, the result is in `[0, modulus)`.
+///
+/// # Panics
+/// If `modulus` is zero.
+#[must_use]
+pub fn mod_pow<T: Integer>(base: T, mut exp: u64, modulus: T) -> T {
+    assert!(modulus != T::ZERO, "Zero modulus");
+    if modulus == T::ONE {
+        return T::ZERO;
+    }
+    let mut base = base.rem_euclid(modulus);
+    let mut res = T::ONE;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            res = res.mul_mod(base, modulus);
+        }
+        base = base.mul_mod(base, modulus);
+        exp >>= 1;
+    }
+    res
+}
+
+/// Solves the system of `x ≡ residue (mod modulus)` congruences.
+///
+/// The moduli don't have to be coprime, returns the smallest non-negative solution
+/// together with the combined modulus (their lcm) or `None` if the congruences contradict,
+/// a modulus is zero or the combined modulus overflows.
+#[must_use]
+pub fn crt<T: SignedInteger>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    congruences
+        .iter()
+        .try_fold((T::ZERO, T::ONE), |(res, modulus), &(residue, m)| {
+            if m == T::ZERO {
+                return None;
+            }
+            let m = m.abs();
+            let residue = residue.rem_euclid(m);
+            let (g, p, _) = extended_gcd(modulus, m);
+            let diff = residue - res;
+            if diff % g != T::ZERO {
+                return None;
+            }
+            let m_g = m / g;
+            let k = (diff / g).mul_mod(p, m_g);
+            let combined = modulus.checked_mul(m_g)?;
+            Some(((res + modulus * k).rem_euclid(combined), combined))
+        })
+}
+
+/// Integer square root (floor).
+///
+/// # Panics
+/// If `n` is negative.
+#[must_use]
+pub fn isqrt<T: Integer>(n: T) -> T {
+    n.isqrt()
+}
+
+/// Integer `k`-th root (floor) of a non-negative `n`.
+///
+/// # Panics
+/// If `n` is negative or `k` is zero.
+#[must_use]
+pub fn iroot<T: Integer>(n: T, k: u32) -> T {
+    assert!(k > 0, "Zeroth root");
+    assert!(n >= T::ZERO, "Root of a negative number");
+    if k == 1 || n <= T::ONE {
+        return n;
+    }
+    let pow_within = |base: T| {
+        (0..k)
+            .try_fold(T::ONE, |acc, _| acc.checked_mul(base))
+            .is_some_and(|pow| pow <= n)
+    };
+    // binary search for the largest root where root^k <= n
+    let (mut low, mut high) = (T::ONE, n);
+    let two = T::ONE + T::ONE;
+    while low < high {
+        let mid = high - (high - low) / two;
+        if pow_within(mid) {
+            low = mid;
+        } else {
+            high = mid - T::ONE;
+        }
+    }
+    low
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(12, 18 => 6)]
+    #[test_case(-12, 18 => 6)]
+    #[test_case(0, 7 => 7)]
+    #[test_case(0, 0 => 0)]
+    #[test_case(17, 5 => 1)]
+    fn gcd_i32(a: i32, b: i32) -> i32 {
+        gcd(a, b)
+    }
+
+    #[test]
+    fn gcd_unsigned() {
+        assert_eq!(4u8, gcd(8u8, 12));
+        assert_eq!(1u128 << 70, gcd(1u128 << 70, 3 << 80));
+        assert_eq!(2usize, gcd(10usize, 4));
+    }
+
+    #[test_case(4, 6 => 12)]
+    #[test_case(-4, 6 => 12)]
+    #[test_case(0, 6 => 0)]
+    #[test_case(101, 103 => 10403)]
+    fn lcm_i64(a: i64, b: i64) -> i64 {
+        lcm(a, b)
+    }
+
+    #[test_case(240, 46)]
+    #[test_case(46, 240)]
+    #[test_case(-7, 3)]
+    #[test_case(0, 5)]
+    #[test_case(6, -9)]
+    fn extended(a: i64, b: i64) {
+        let (divisor, coef_a, coef_b) = extended_gcd(a, b);
+        assert_eq!(gcd(a, b), divisor);
+        assert_eq!(divisor, a * coef_a + b * coef_b);
+    }
+
+    #[test_case(3, 11 => Some(4))]
+    #[test_case(-3, 11 => Some(7))]
+    #[test_case(10, 17 => Some(12))]
+    #[test_case(6, 9 => None)]
+    #[test_case(1, 1 => Some(0))]
+    #[test_case(3, 0 => None)]
+    fn inverse(a: i32, m: i32) -> Option<i32> {
+        mod_inv(a, m)
+    }
+
+    #[test_case(2, 10, 1000 => 24)]
+    #[test_case(3, 0, 7 => 1)]
+    #[test_case(-2, 3, 5 => 2)]
+    #[test_case(5, 3, 1 => 0)]
+    fn pow_i64(base: i64, exp: u64, m: i64) -> i64 {
+        mod_pow(base, exp, m)
+    }
+
+    #[test]
+    fn pow_no_overflow() {
+        // Fermat's little theorem with a prime close to the type limits
+        let p = 18_446_744_073_709_551_557u64;
+        assert_eq!(1, mod_pow(123_456_789u64, p - 1, p));
+        // 2^127 - 1 is a Mersenne prime
+        let p = (1u128 << 127) - 1;
+        assert_eq!(1, mod_pow(2u128, 127, p));
+        assert_eq!(2, mod_pow(2u128, 128, p));
+        assert_eq!(1, mod_pow(2i128, 254, p as i128));
+    }
+
+    #[test]
+    #[should_panic(expected = "Zero modulus")]
+    fn pow_zero_modulus() {
+        let _ = mod_pow(2u64, 3, 0);
+    }
+
+    #[test_case(&[(2, 3), (3, 5), (2, 7)] => Some((23, 105)))]
+    #[test_case(&[(0, 101), (0, 103)] => Some((0, 10403)))]
+    #[test_case(&[(1, 4), (3, 6)] => Some((9, 12)))]
+    #[test_case(&[(1, 4), (2, 6)] => None)]
+    #[test_case(&[(-1, 5)] => Some((4, 5)))]
+    #[test_case(&[] => Some((0, 1)))]
+    #[test_case(&[(1, 4), (0, 0)] => None)]
+    #[test_case(&[(1, i64::MAX), (1, i64::MAX - 1)] => None)]
+    fn chinese_remainder(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+        crt(congruences)
+    }
+
+    #[test]
+    fn crt_robot_periods() {
+        // x-axis repeats every 101 steps and y-axis every 103 steps
+        let (res, modulus) = crt(&[(12i64, 101), (68, 103)]).unwrap();
+        assert_eq!(10403, modulus);
+        assert_eq!((12, 68), (res % 101, res % 103));
+    }
+
+    #[test_case(0 => 0)]
+    #[test_case(1 => 1)]
+    #[test_case(15 => 3)]
+    #[test_case(16 => 4)]
+    #[test_case(u64::MAX => 4_294_967_295)]
+    fn sqrt_u64(n: u64) -> u64 {
+        isqrt(n)
+    }
+
+    #[test_case(27, 3 => 3)]
+    #[test_case(26, 3 => 2)]
+    #[test_case(1 << 40, 4 => 1024)]
+    #[test_case(u64::MAX, 2 => 4_294_967_295)]
+    #[test_case(u64::MAX, 64 => 1)]
+    #[test_case(7, 1 => 7)]
+    fn root_u64(n: u64, k: u32) -> u64 {
+        iroot(n, k)
+    }
+}
@@ -0,0 +1,221 @@
+type BinaryFn<T> = Box<dyn Fn(T, T) -> Option<T> + Send + Sync>;
+type InverseFn<T> = Box<dyn Fn(T, T) -> Inverse<T> + Send + Sync>;
+
+struct RegisteredOperator<T, O> {
+    operator: O,
+    apply: BinaryFn<T>,
+    inverse: Option<InverseFn<T>>,
+}
+
+/// The `lhs` values for which `lhs op rhs == result`, as returned by an inverse operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Inverse<T> {
+    Impossible,
+    Unique(T),
+    /// Every `lhs` works, e.g. for `lhs * 0 == 0`.
+    Any,
+}
+impl<T> From<Option<T>> for Inverse<T> {
+    fn from(lhs: Option<T>) -> Self {
+        lhs.map_or(Self::Impossible, Self::Unique)
+    }
+}
+
+/// Finds operators that combine numbers (evaluated left to right) into a target value.
+///
+/// When every operator has an inverse the search runs backwards from the target, undoing the
+/// operators from the last number on, which prunes most branches right away. Otherwise it tries
+/// all the operator sequences forwards. The numbers before an operator whose inverse allows
+/// [`Inverse::Any`] lhs are searched forwards as well.
+pub struct OperatorSearch<T, O> {
+    operators: Vec<RegisteredOperator<T, O>>,
+}
+impl<T, O> Default for OperatorSearch<T, O> {
+    fn default() -> Self {
+        Self {
+            operators: Vec::new(),
+        }
+    }
+}
+impl<T: Copy + PartialEq, O: Clone> OperatorSearch<T, O> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the `operator` label with its application `lhs op rhs` (`None` if undefined, e.g. overflow).
+    #[must_use]
+    pub fn operator(
+        mut self,
+        operator: O,
+        apply: impl Fn(T, T) -> Option<T> + Send + Sync + 'static,
+    ) -> Self {
+        self.operators.push(RegisteredOperator {
+            operator,
+            apply: Box::new(apply),
+            inverse: None,
+        });
+        self
+    }
+
+    /// Sets the inverse of the last registered operator, it returns the [`Inverse`] `lhs` for
+    /// which `lhs op rhs == result` given `(result, rhs)`, an `Option` works for a unique `lhs`.
+    ///
+    /// # Panics
+    /// If no operator was registered yet.
+    #[must_use]
+    pub fn with_inverse<I: Into<Inverse<T>>>(
+        mut self,
+        inverse: impl Fn(T, T) -> I + Send + Sync + 'static,
+    ) -> Self {
+        let last = self
+            .operators
+            .last_mut()
+            .expect("An operator to set the inverse of");
+        last.inverse = Some(Box::new(move |result, rhs| inverse(result, rhs).into()));
+        self
+    }
+
+    /// Whether the search runs backwards from the target.
+    #[must_use]
+    pub fn is_backwards(&self) -> bool {
+        self.operators.iter().all(|op| op.inverse.is_some())
+    }
+
+    /// The operators to put between the `numbers` to get the `target`, if there are any.
+    #[must_use]
+    pub fn solve(&self, numbers: &[T], target: T) -> Option<Vec<O>> {
+        let (first, rest) = numbers.split_first()?;
+        let mut operators = Vec::with_capacity(rest.len());
+        let found = if self.is_backwards() {
+            self.backwards(*first, rest, target, &mut operators)
+        } else {
+            self.forwards(*first, rest, Some(target), &mut operators)
+        };
+        found.then(|| {
+            if self.is_backwards() {
+                operators.reverse();
+            }
+            operators
+                .into_iter()
+                .map(|i| self.operators[i].operator.clone())
+                .collect()
+        })
+    }
+
+    // `operators` gets the operator indices in the order they're applied, any result is a
+    // match without a `target`
+    fn forwards(&self, acc: T, rest: &[T], target: Option<T>, operators: &mut Vec<usize>) -> bool {
+        let Some((next, rest)) = rest.split_first() else {
+            return target.is_none_or(|target| acc == target);
+        };
+        self.operators.iter().enumerate().any(|(i, op)| {
+            (op.apply)(acc, *next).is_some_and(|acc| {
+                operators.push(i);
+                let found = self.forwards(acc, rest, target, operators);
+                if !found {
+                    operators.pop();
+                }
+                found
+            })
+        })
+    }
+
+    // `operators` gets the operator indices from the last applied one
+    fn backwards(&self, first: T, rest: &[T], target: T, operators: &mut Vec<usize>) -> bool {
+        let Some((last, rest)) = rest.split_last() else {
+            return first == target;
+        };
+        self.operators.iter().enumerate().any(|(i, op)| {
+            let inverse = op.inverse.as_ref().expect("Invertible operator");
+            let len = operators.len();
+            operators.push(i);
+            let found = match inverse(target, *last) {
+                Inverse::Impossible => false,
+                Inverse::Unique(target) => self.backwards(first, rest, target, operators),
+                Inverse::Any => {
+                    let mut prefix = Vec::with_capacity(rest.len());
+                    let found = self.forwards(first, rest, None, &mut prefix);
+                    operators.extend(prefix.into_iter().rev());
+                    found
+                }
+            };
+            if !found {
+                operators.truncate(len);
+            }
+            found
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Op {
+        Add,
+        Mul,
+    }
+
+    fn search(invertible: bool) -> OperatorSearch<u64, Op> {
+        let search = OperatorSearch::new()
+            .operator(Op::Add, u64::checked_add)
+            .with_inverse(u64::checked_sub)
+            .operator(Op::Mul, u64::checked_mul);
+        if invertible {
+            search.with_inverse(|res, rhs| match (res, rhs) {
+                (0, 0) => Inverse::Any,
+                (_, 0) => Inverse::Impossible,
+                _ => (res % rhs == 0).then(|| res / rhs).into(),
+            })
+        } else {
+            search
+        }
+    }
+
+    #[test_case(&[10, 19], 190 => Some(vec![Op::Mul]))]
+    #[test_case(&[81, 40, 27], 3267 => Some(vec![Op::Add, Op::Mul]))]
+    #[test_case(&[11, 6, 16, 20], 292 => Some(vec![Op::Add, Op::Mul, Op::Add]))]
+    #[test_case(&[17, 5], 83 => None)]
+    #[test_case(&[7], 7 => Some(vec![]))]
+    #[test_case(&[], 0 => None)]
+    #[test_case(&[5, 0], 0 => Some(vec![Op::Mul]))]
+    #[test_case(&[5, 0], 5 => Some(vec![Op::Add]))]
+    #[test_case(&[3, 4, 0, 2], 2 => Some(vec![Op::Add, Op::Mul, Op::Add]))]
+    #[test_case(&[3, 4, 0, 2], 3 => None)]
+    fn both_directions(numbers: &[u64], target: u64) -> Option<Vec<Op>> {
+        let (backwards, forwards) = (search(true), search(false));
+        assert!(backwards.is_backwards() && !forwards.is_backwards());
+        // both are valid for 3267, the searches just try the last operator first or the first one
+        let res = forwards.solve(numbers, target);
+        assert_eq!(res.is_some(), backwards.solve(numbers, target).is_some());
+        res
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(None, search(false).solve(&[u64::MAX, 2], 0));
+    }
+
+    #[test_case(&[3, 5, 2, 7, 4, 1, 9])]
+    #[test_case(&[3, 0, 2, 7, 0, 1, 9])]
+    fn sequence_evaluates_to_target(numbers: &[u64]) {
+        let (backwards, forwards) = (search(true), search(false));
+        for target in 0..2000 {
+            let found = backwards.solve(numbers, target);
+            assert_eq!(found.is_some(), forwards.solve(numbers, target).is_some());
+            if let Some(ops) = found {
+                let res = ops
+                    .iter()
+                    .zip(&numbers[1..])
+                    .fold(numbers[0], |acc, (op, n)| match op {
+                        Op::Add => acc + n,
+                        Op::Mul => acc * n,
+                    });
+                assert_eq!(target, res);
+            }
+        }
+    }
+}
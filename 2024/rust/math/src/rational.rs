@@ -0,0 +1,356 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use anyhow::{bail, Context};
+
+use crate::{gcd, SignedInteger};
+
+/// Exact fraction, always kept reduced with a positive denominator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational<T = i128> {
+    numer: T,
+    denom: T,
+}
+impl<T: SignedInteger> Rational<T> {
+    pub const ZERO: Self = Self {
+        numer: T::ZERO,
+        denom: T::ONE,
+    };
+    pub const ONE: Self = Self {
+        numer: T::ONE,
+        denom: T::ONE,
+    };
+
+    /// # Panics
+    /// If the denominator is zero.
+    #[must_use]
+    pub fn new(numer: T, denom: T) -> Self {
+        Self::checked_new(numer, denom).expect("Zero denominator")
+    }
+
+    /// Reduced `numer / denom`, `None` for a zero denominator.
+    #[must_use]
+    pub fn checked_new(numer: T, denom: T) -> Option<Self> {
+        if denom == T::ZERO {
+            return None;
+        }
+        let divisor = gcd(numer, denom);
+        let divisor = if denom < T::ZERO { -divisor } else { divisor };
+        Some(Self {
+            numer: numer / divisor,
+            denom: denom / divisor,
+        })
+    }
+
+    #[must_use]
+    pub const fn from_integer(value: T) -> Self {
+        Self {
+            numer: value,
+            denom: T::ONE,
+        }
+    }
+
+    #[must_use]
+    pub const fn numer(&self) -> T {
+        self.numer
+    }
+
+    #[must_use]
+    pub const fn denom(&self) -> T {
+        self.denom
+    }
+
+    #[must_use]
+    pub fn is_integer(&self) -> bool {
+        self.denom == T::ONE
+    }
+
+    /// The value if it's a whole number.
+    #[must_use]
+    pub fn to_integer(&self) -> Option<T> {
+        self.is_integer().then_some(self.numer)
+    }
+
+    /// Largest integer not greater than the value.
+    #[must_use]
+    pub fn floor(&self) -> T {
+        (self.numer - self.numer.rem_euclid(self.denom)) / self.denom
+    }
+
+    /// Smallest integer not less than the value.
+    #[must_use]
+    pub fn ceil(&self) -> T {
+        -(-*self).floor()
+    }
+
+    #[must_use]
+    pub fn abs(&self) -> Self {
+        Self {
+            numer: self.numer.abs(),
+            denom: self.denom,
+        }
+    }
+
+    /// # Panics
+    /// If the value is zero.
+    #[must_use]
+    pub fn recip(&self) -> Self {
+        Self::new(self.denom, self.numer)
+    }
+
+    #[must_use]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        // add over the lcm of the denominators to keep the intermediates small
+        let divisor = gcd(self.denom, rhs.denom);
+        let (lhs_factor, rhs_factor) = (rhs.denom / divisor, self.denom / divisor);
+        let numer = self
+            .numer
+            .checked_mul(lhs_factor)?
+            .checked_add(rhs.numer.checked_mul(rhs_factor)?)?;
+        Self::checked_new(numer, self.denom.checked_mul(lhs_factor)?)
+    }
+
+    #[must_use]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(-rhs)
+    }
+
+    #[must_use]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        // cross reduce first, the result is then already in lowest terms
+        let divisor_a = gcd(self.numer, rhs.denom);
+        let divisor_b = gcd(rhs.numer, self.denom);
+        Some(Self {
+            numer: (self.numer / divisor_a).checked_mul(rhs.numer / divisor_b)?,
+            denom: (self.denom / divisor_b).checked_mul(rhs.denom / divisor_a)?,
+        })
+    }
+
+    /// `None` on overflow or division by zero.
+    #[must_use]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.numer == T::ZERO {
+            return None;
+        }
+        self.checked_mul(rhs.recip())
+    }
+}
+
+impl<T: SignedInteger> From<T> for Rational<T> {
+    fn from(value: T) -> Self {
+        Self::from_integer(value)
+    }
+}
+
+impl<T: SignedInteger> Neg for Rational<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            numer: -self.numer,
+            denom: self.denom,
+        }
+    }
+}
+
+macro_rules! impl_rational_op {
+    ($($op: ident, $fn: ident, $assign_op: ident, $assign_fn: ident, $checked: ident, $msg: literal);*) => {
+        $(
+            impl<T: SignedInteger> $op for Rational<T> {
+                type Output = Self;
+
+                fn $fn(self, rhs: Self) -> Self::Output {
+                    self.$checked(rhs).expect($msg)
+                }
+            }
+
+            impl<T: SignedInteger> $op<T> for Rational<T> {
+                type Output = Self;
+
+                fn $fn(self, rhs: T) -> Self::Output {
+                    self.$checked(Self::from_integer(rhs)).expect($msg)
+                }
+            }
+
+            impl<T: SignedInteger> $assign_op for Rational<T> {
+                fn $assign_fn(&mut self, rhs: Self) {
+                    *self = $op::$fn(*self, rhs);
+                }
+            }
+        )*
+    };
+}
+
+impl_rational_op!(
+    Add, add, AddAssign, add_assign, checked_add, "Rational addition overflow";
+    Sub, sub, SubAssign, sub_assign, checked_sub, "Rational subtraction overflow";
+    Mul, mul, MulAssign, mul_assign, checked_mul, "Rational multiplication overflow";
+    Div, div, DivAssign, div_assign, checked_div, "Rational division overflow or by zero"
+);
+
+impl<T: SignedInteger> PartialOrd for Rational<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: SignedInteger> Ord for Rational<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // compare the integer parts first so that the cross products can't overflow
+        let (floor, other_floor) = (self.floor(), other.floor());
+        if floor != other_floor {
+            return floor.cmp(&other_floor);
+        }
+        let fract = self.numer - floor * self.denom;
+        let other_fract = other.numer - other_floor * other.denom;
+        match (
+            fract.checked_mul(other.denom),
+            other_fract.checked_mul(self.denom),
+        ) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            _ if fract == T::ZERO || other_fract == T::ZERO => fract.cmp(&other_fract),
+            // both fractional parts are in `[0, 1)`, compare the reciprocals instead
+            _ => Self::new(other.denom, other_fract).cmp(&Self::new(self.denom, fract)),
+        }
+    }
+}
+
+impl<T: SignedInteger> Display for Rational<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+impl<T> FromStr for Rational<T>
+where
+    T: SignedInteger + FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    type Err = anyhow::Error;
+
+    /// Parses `numer/denom` or a plain integer (surrounding whitespace is allowed).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_int = |s: &str| {
+            s.trim()
+                .parse::<T>()
+                .with_context(|| format!("Invalid rational number part {s:?}"))
+        };
+        match s.split_once('/') {
+            Some((numer, denom)) => {
+                let denom = parse_int(denom)?;
+                if denom == T::ZERO {
+                    bail!("Zero denominator in {s:?}");
+                }
+                Ok(Self::new(parse_int(numer)?, denom))
+            }
+            None => Ok(Self::from_integer(parse_int(s)?)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn r(numer: i128, denom: i128) -> Rational {
+        Rational::new(numer, denom)
+    }
+
+    #[test_case(2, 4 => (1, 2))]
+    #[test_case(3, -6 => (-1, 2))]
+    #[test_case(-3, -6 => (1, 2))]
+    #[test_case(0, -5 => (0, 1))]
+    #[test_case(7, 1 => (7, 1))]
+    fn normalise(numer: i128, denom: i128) -> (i128, i128) {
+        let val = r(numer, denom);
+        (val.numer(), val.denom())
+    }
+
+    #[test]
+    fn zero_denominator() {
+        assert_eq!(None, Rational::checked_new(1i64, 0));
+        assert_eq!(None, r(1, 2).checked_div(Rational::ZERO));
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(r(5, 6), r(1, 2) + r(1, 3));
+        assert_eq!(r(1, 6), r(1, 2) - r(1, 3));
+        assert_eq!(r(1, 6), r(1, 2) * r(1, 3));
+        assert_eq!(r(3, 2), r(1, 2) / r(1, 3));
+        assert_eq!(r(-1, 2), -r(1, 2));
+        assert_eq!(r(7, 2), r(1, 2) + 3);
+        assert_eq!(Rational::ZERO, r(3, 7) * Rational::ZERO);
+        let mut acc = Rational::<i32>::ZERO;
+        for i in 1..=10 {
+            acc += Rational::new(1, i * (i + 1));
+        }
+        assert_eq!(Rational::new(10, 11), acc);
+    }
+
+    #[test]
+    fn overflow() {
+        let big = Rational::from_integer(i64::MAX);
+        assert_eq!(None, big.checked_add(Rational::ONE));
+        assert_eq!(None, big.checked_mul(big));
+        // cross reduction keeps this within range
+        assert_eq!(
+            Some(Rational::ONE),
+            Rational::new(i64::MAX, 3).checked_mul(Rational::new(3, i64::MAX))
+        );
+    }
+
+    #[test_case(7, 2 => (3, 4))]
+    #[test_case(-7, 2 => (-4, -3))]
+    #[test_case(6, 3 => (2, 2))]
+    #[test_case(-1, 3 => (-1, 0))]
+    fn rounding(numer: i128, denom: i128) -> (i128, i128) {
+        let val = r(numer, denom);
+        (val.floor(), val.ceil())
+    }
+
+    #[test]
+    fn integer_conversion() {
+        assert_eq!(Some(4), r(8, 2).to_integer());
+        assert_eq!(None, r(8, 3).to_integer());
+    }
+
+    #[test]
+    fn ordering() {
+        let mut values = vec![r(1, 2), r(-3, 4), r(2, 3), r(5, 1), r(-1, 1)];
+        values.sort();
+        assert_eq!(vec![r(-1, 1), r(-3, 4), r(1, 2), r(2, 3), r(5, 1)], values);
+        // cross products of these overflow i128
+        let (a, b) = (
+            Rational::new(i128::MAX - 1, i128::MAX),
+            Rational::new(i128::MAX - 2, i128::MAX - 1),
+        );
+        assert!(b < a);
+    }
+
+    #[test_case("3/4" => Some(r(3, 4)))]
+    #[test_case(" -6 / 8 " => Some(r(-3, 4)))]
+    #[test_case("42" => Some(r(42, 1)))]
+    #[test_case("1/0" => None)]
+    #[test_case("x/2" => None)]
+    #[test_case("" => None)]
+    fn parse(input: &str) -> Option<Rational> {
+        input.parse().ok()
+    }
+
+    #[test_case(r(3, 4) => "3/4")]
+    #[test_case(r(-6, 3) => "-2")]
+    #[test_case(Rational::ZERO => "0")]
+    fn format(val: Rational) -> String {
+        val.to_string()
+    }
+}
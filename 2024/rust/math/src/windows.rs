@@ -0,0 +1,227 @@
+use rayon::prelude::*;
+
+/// Aggregates the values following every window of `len` consecutive small deltas across many sequences.
+///
+/// The deltas (within `-max_delta..=max_delta`) of a window are packed into a dense key, so the
+/// totals live in a flat array instead of a map. Only the first occurrence of a window in each
+/// sequence counts, e.g. a buyer sells at the first time the price changes match.
+#[derive(Debug, Clone, Copy)]
+pub struct WindowAggregator {
+    len: u32,
+    max_delta: i64,
+}
+
+/// Per window totals of [`WindowAggregator::aggregate`].
+#[derive(Debug, Clone)]
+pub struct WindowTotals {
+    aggregator: WindowAggregator,
+    totals: Vec<i64>,
+    // whether a window has the key, the totals of the others are 0 but no window
+    occurred: Vec<bool>,
+}
+
+// per thread accumulator, `seen` holds the (1-based) index of the last sequence with the key
+struct Accumulator {
+    totals: Vec<i64>,
+    seen: Vec<u32>,
+    sequence: u32,
+}
+
+impl WindowAggregator {
+    /// # Panics
+    /// If the key space (`(2 * max_delta + 1)^len`) doesn't fit in memory indices.
+    #[must_use]
+    pub fn new(len: u32, max_delta: i64) -> Self {
+        let aggregator = Self { len, max_delta };
+        assert!(
+            aggregator.base().checked_pow(len).is_some(),
+            "Window key space overflow"
+        );
+        aggregator
+    }
+
+    fn base(&self) -> usize {
+        (self.max_delta * 2 + 1) as usize
+    }
+
+    /// Number of distinct window keys.
+    #[must_use]
+    pub fn key_space(&self) -> usize {
+        self.base().pow(self.len)
+    }
+
+    /// Packs the window deltas (oldest first) into its key.
+    ///
+    /// # Panics
+    /// If a delta is out of range or the window length doesn't match.
+    #[must_use]
+    pub fn encode(&self, deltas: &[i64]) -> usize {
+        assert_eq!(self.len as usize, deltas.len(), "Window length mismatch");
+        deltas
+            .iter()
+            .fold(0, |key, delta| self.push_delta(key, *delta))
+    }
+
+    /// Unpacks the window deltas (oldest first) of the key.
+    #[must_use]
+    pub fn decode(&self, mut key: usize) -> Vec<i64> {
+        let mut deltas: Vec<_> = (0..self.len)
+            .map(|_| {
+                let digit = key % self.base();
+                key /= self.base();
+                digit as i64 - self.max_delta
+            })
+            .collect();
+        deltas.reverse();
+        deltas
+    }
+
+    fn push_delta(&self, key: usize, delta: i64) -> usize {
+        assert!(
+            delta.abs() <= self.max_delta,
+            "Window delta {delta} out of range"
+        );
+        (key * self.base() + (delta + self.max_delta) as usize) % self.key_space()
+    }
+
+    /// Sums the value ending the first occurrence of every window of each sequence, in parallel over the sequences.
+    ///
+    /// # Panics
+    /// If a delta between consecutive values is out of range.
+    pub fn aggregate<S, I>(&self, sequences: S) -> WindowTotals
+    where
+        S: IntoParallelIterator<Item = I>,
+        I: IntoIterator<Item = i64>,
+    {
+        let new_accumulator = || Accumulator {
+            totals: vec![0; self.key_space()],
+            seen: vec![0; self.key_space()],
+            sequence: 0,
+        };
+        let (totals, occurred) = sequences
+            .into_par_iter()
+            .fold(new_accumulator, |mut acc, sequence| {
+                acc.sequence += 1;
+                let mut values = sequence.into_iter();
+                let Some(mut prev) = values.next() else {
+                    return acc;
+                };
+                let mut key = 0;
+                for (i, value) in values.enumerate() {
+                    key = self.push_delta(key, value - prev);
+                    prev = value;
+                    if i + 1 >= self.len as usize && acc.seen[key] != acc.sequence {
+                        acc.seen[key] = acc.sequence;
+                        acc.totals[key] += value;
+                    }
+                }
+                acc
+            })
+            .map(|acc| {
+                let occurred = acc.seen.iter().map(|sequence| *sequence != 0).collect();
+                (acc.totals, occurred)
+            })
+            .reduce_with(|(mut a, mut a_occurred), (b, b_occurred): (_, Vec<_>)| {
+                a.iter_mut().zip(b).for_each(|(a, b)| *a += b);
+                a_occurred
+                    .iter_mut()
+                    .zip(b_occurred)
+                    .for_each(|(a, b)| *a |= b);
+                (a, a_occurred)
+            })
+            .unwrap_or_else(|| (vec![0; self.key_space()], vec![false; self.key_space()]));
+        WindowTotals {
+            aggregator: *self,
+            totals,
+            occurred,
+        }
+    }
+}
+
+impl WindowTotals {
+    /// The total of the window with the deltas.
+    #[must_use]
+    pub fn get(&self, deltas: &[i64]) -> i64 {
+        self.totals[self.aggregator.encode(deltas)]
+    }
+
+    /// The window deltas with the highest total among the windows that occurred (the first such
+    /// key on ties), `None` if no sequence was long enough for a window.
+    #[must_use]
+    pub fn best(&self) -> Option<(Vec<i64>, i64)> {
+        let (key, total) = self
+            .totals
+            .iter()
+            .enumerate()
+            .filter(|(key, _)| self.occurred[*key])
+            .max_by_key(|(key, total)| (**total, std::cmp::Reverse(*key)))?;
+        Some((self.aggregator.decode(key), *total))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(&[-2, 1, -1, 3])]
+    #[test_case(&[0, 0, 0, 0])]
+    #[test_case(&[9, -9, 9, -9])]
+    fn encode_roundtrip(deltas: &[i64]) {
+        let aggregator = WindowAggregator::new(4, 9);
+        assert_eq!(130_321, aggregator.key_space());
+        let key = aggregator.encode(deltas);
+        assert!(key < aggregator.key_space());
+        assert_eq!(deltas, aggregator.decode(key));
+    }
+
+    #[test]
+    fn first_occurrence_only() {
+        let aggregator = WindowAggregator::new(2, 3);
+        // deltas +1 +1 appear twice in the first sequence, only the first (value 2) counts
+        let totals = aggregator.aggregate(vec![vec![0, 1, 2, 3], vec![5, 6, 7, 4, 1]]);
+        assert_eq!(2 + 7, totals.get(&[1, 1]));
+        assert_eq!(1, totals.get(&[-3, -3]));
+        assert_eq!(0, totals.get(&[2, 2]));
+        assert_eq!(Some((vec![1, 1], 9)), totals.best());
+    }
+
+    #[test]
+    fn short_sequences() {
+        let aggregator = WindowAggregator::new(3, 1);
+        let totals = aggregator.aggregate(vec![vec![], vec![1], vec![1, 2, 3]]);
+        assert!(totals.totals.iter().all(|t| *t == 0));
+        assert_eq!(None, totals.best());
+        let totals = aggregator.aggregate(vec![vec![1, 2, 3], vec![0, 0, 0, 0]]);
+        assert_eq!(Some((vec![0, 0, 0], 0)), totals.best());
+        // windows with negative totals still beat the keys no window had
+        let totals = aggregator.aggregate(vec![vec![0, -1, -2, -3]]);
+        assert_eq!(Some((vec![-1, -1, -1], -3)), totals.best());
+        assert_eq!(None, aggregator.aggregate(Vec::<Vec<i64>>::new()).best());
+    }
+
+    #[test]
+    fn matches_map_per_sequence() {
+        let sequences: Vec<Vec<i64>> = (1..50i64)
+            .map(|seed| (0..200).map(|i| (seed * 7 + i * i * 13) % 10).collect())
+            .collect();
+        let totals = WindowAggregator::new(3, 9).aggregate(sequences.clone());
+        let mut expected = std::collections::HashMap::new();
+        for sequence in &sequences {
+            let mut seen = std::collections::HashSet::new();
+            for window in sequence.windows(4) {
+                let deltas: Vec<_> = window.windows(2).map(|w| w[1] - w[0]).collect();
+                if seen.insert(deltas.clone()) {
+                    *expected.entry(deltas).or_insert(0) += window[3];
+                }
+            }
+        }
+        for (deltas, total) in &expected {
+            assert_eq!(*total, totals.get(deltas));
+        }
+        assert_eq!(
+            expected.values().max(),
+            totals.best().map(|(_, t)| t).as_ref()
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use std::{collections::HashMap, hash::Hash};
+
+use tracing::debug;
+
+/// Hit/miss counters of a [`Memo`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MemoStats {
+    pub hits: u64,
+    pub misses: u64,
+    /// Times a bounded cache was full and got cleared.
+    pub evictions: u64,
+    pub entries: usize,
+}
+impl MemoStats {
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            total => self.hits as f64 / total as f64,
+        }
+    }
+}
+
+/// Memoization cache for recursive functions.
+///
+/// The computation closure gets the memo itself to recurse through, so the cache is shared
+/// between all the calls (and can be reused across inputs):
+/// ```
+/// let mut fib = memo::Memo::new("fib");
+/// fn calc(memo: &mut memo::Memo<u64, u64>, n: u64) -> u64 {
+///     memo.get_or_compute(n, |memo, n| if n < 2 { n } else { calc(memo, n - 1) + calc(memo, n - 2) })
+/// }
+/// assert_eq!(12_586_269_025, calc(&mut fib, 50));
+/// ```
+#[derive(Debug)]
+pub struct Memo<K, V> {
+    name: &'static str,
+    cache: HashMap<K, V>,
+    capacity: Option<usize>,
+    stats: MemoStats,
+}
+impl<K: Hash + Eq + Clone, V: Clone> Memo<K, V> {
+    /// Unbounded cache, the `name` identifies it in the traced stats.
+    #[must_use]
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            cache: HashMap::new(),
+            capacity: None,
+            stats: MemoStats::default(),
+        }
+    }
+
+    /// Cache holding at most `capacity` entries, it's cleared whenever it's full (and a
+    /// `capacity` of 0 never stores anything).
+    #[must_use]
+    pub fn bounded(name: &'static str, capacity: usize) -> Self {
+        Self {
+            name,
+            cache: HashMap::with_capacity(capacity),
+            capacity: Some(capacity),
+            stats: MemoStats::default(),
+        }
+    }
+
+    /// Returns the cached value or computes (and caches) it with `compute`.
+    pub fn get_or_compute(&mut self, key: K, compute: impl FnOnce(&mut Self, K) -> V) -> V {
+        if let Some(val) = self.cache.get(&key) {
+            self.stats.hits += 1;
+            return val.clone();
+        }
+        self.stats.misses += 1;
+        let val = compute(self, key.clone());
+        match self.capacity {
+            Some(0) => return val,
+            Some(capacity) if self.cache.len() >= capacity => {
+                self.cache.clear();
+                self.stats.evictions += 1;
+            }
+            _ => {}
+        }
+        self.cache.insert(key, val.clone());
+        val
+    }
+
+    #[must_use]
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    #[must_use]
+    pub fn stats(&self) -> MemoStats {
+        MemoStats {
+            entries: self.cache.len(),
+            ..self.stats
+        }
+    }
+
+    /// Emits the current stats as a `debug` event.
+    pub fn trace_stats(&self) {
+        let stats = self.stats();
+        debug!(
+            memo = self.name,
+            hits = stats.hits,
+            misses = stats.misses,
+            evictions = stats.evictions,
+            entries = stats.entries,
+            hit_rate = format!("{:.1}%", stats.hit_rate() * 100.0),
+            "memo stats"
+        );
+    }
+
+    /// Drops all the entries and resets the stats.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.stats = MemoStats::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+    use tracing_test::traced_test;
+
+    fn paths(memo: &mut Memo<(u32, u32), u64>, x: u32, y: u32) -> u64 {
+        memo.get_or_compute((x, y), |memo, (x, y)| match (x, y) {
+            (0, _) | (_, 0) => 1,
+            _ => paths(memo, x - 1, y) + paths(memo, x, y - 1),
+        })
+    }
+
+    #[test_case(1, 1 => 2)]
+    #[test_case(2, 2 => 6)]
+    #[test_case(16, 16 => 601_080_390)]
+    fn lattice_paths(x: u32, y: u32) -> u64 {
+        paths(&mut Memo::new("paths"), x, y)
+    }
+
+    #[test]
+    fn shared_between_calls() {
+        let mut memo = Memo::new("paths");
+        paths(&mut memo, 10, 10);
+        let misses = memo.stats().misses;
+        assert_eq!(121 - 1, misses);
+        assert_eq!(184_756, paths(&mut memo, 10, 10));
+        assert_eq!(20, paths(&mut memo, 3, 3));
+        assert_eq!(misses, memo.stats().misses);
+        assert_eq!(Some(&184_756), memo.get(&(10, 10)));
+    }
+
+    #[test]
+    fn bounded() {
+        let mut memo = Memo::bounded("paths", 8);
+        assert_eq!(601_080_390, paths(&mut memo, 16, 16));
+        let stats = memo.stats();
+        assert!(stats.entries <= 8);
+        assert!(stats.evictions > 0);
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut memo = Memo::bounded("paths", 0);
+        assert_eq!(20, paths(&mut memo, 3, 3));
+        let stats = memo.stats();
+        assert_eq!((0, 0, 0), (stats.entries, stats.hits, stats.evictions));
+        assert_eq!(None, memo.get(&(3, 3)));
+    }
+
+    #[test]
+    #[traced_test]
+    fn traced() {
+        let mut memo = Memo::new("paths");
+        paths(&mut memo, 2, 2);
+        memo.trace_stats();
+        assert!(logs_contain("memo=\"paths\" hits=1 misses=8"));
+        memo.clear();
+        assert_eq!(MemoStats::default(), memo.stats());
+    }
+}
@@ -0,0 +1,306 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, spanned::Spanned, Attribute, Data, DeriveInput, Fields, GenericArgument,
+    Ident, LitStr, PathArguments, Type,
+};
+
+#[derive(Debug, PartialEq)]
+enum Segment {
+    Literal(String),
+    Field {
+        name: String,
+        separator: Option<String>,
+    },
+}
+
+/// Derives `parse::AocParse` from a `#[aoc(format = "...")]` attribute on a struct or on every enum variant.
+///
+/// `{field}` parses the field with its `AocParse` impl, `{field:sep}` parses a `sep` separated list
+/// collected into the field's collection type and `{{`/`}}` are escaped braces.
+/// Spaces in literals and separators match any non-empty run of spaces/tabs.
+#[proc_macro_derive(AocParse, attributes(aoc))]
+pub fn derive_aoc_parse(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn derive(input: &DeriveInput) -> syn::Result<TokenStream> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            input.generics.span(),
+            "AocParse can't be derived for generic types",
+        ));
+    }
+    let ident = &input.ident;
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let format = format_attr(&input.attrs, ident.span())?;
+            variant_parser(&quote!(Self), &data.fields, &format)?
+        }
+        Data::Enum(data) => {
+            let variants = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let format = format_attr(&variant.attrs, variant.ident.span())?;
+                    let variant_ident = &variant.ident;
+                    let parser =
+                        variant_parser(&quote!(#ident::#variant_ident), &variant.fields, &format)?;
+                    let parse_variant =
+                        format_ident!("parse_{}", variant_ident.to_string().to_lowercase());
+                    Ok(quote! {
+                        fn #parse_variant(input: &str) -> ::parse::nom::IResult<&str, #ident> {
+                            #parser
+                        }
+                        match #parse_variant(input) {
+                            Err(::parse::nom::Err::Error(_)) => {}
+                            res => return res,
+                        }
+                    })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            quote! {
+                #(#variants)*
+                Err(::parse::nom::Err::Error(::parse::nom::error::Error::new(
+                    input,
+                    ::parse::nom::error::ErrorKind::Alt,
+                )))
+            }
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new(
+                ident.span(),
+                "AocParse can't be derived for unions",
+            ))
+        }
+    };
+    Ok(quote! {
+        impl ::parse::AocParse for #ident {
+            fn parse_nom(input: &str) -> ::parse::nom::IResult<&str, Self> {
+                #body
+            }
+        }
+    })
+}
+
+fn format_attr(attrs: &[Attribute], span: Span) -> syn::Result<LitStr> {
+    let mut format = None;
+    for attr in attrs.iter().filter(|a| a.path().is_ident("aoc")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("format") {
+                format = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported aoc attribute"))
+            }
+        })?;
+    }
+    format.ok_or_else(|| syn::Error::new(span, "missing #[aoc(format = \"...\")] attribute"))
+}
+
+fn variant_parser(
+    ctor: &TokenStream,
+    fields: &Fields,
+    format: &LitStr,
+) -> syn::Result<TokenStream> {
+    let segments =
+        parse_format(&format.value()).map_err(|msg| syn::Error::new(format.span(), msg))?;
+    let field_names: Vec<_> = fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| {
+            f.ident
+                .as_ref()
+                .map_or_else(|| i.to_string(), ToString::to_string)
+        })
+        .collect();
+    let mut used = vec![false; field_names.len()];
+    let mut steps = Vec::with_capacity(segments.len());
+    for segment in segments {
+        match segment {
+            Segment::Literal(lit) => steps.push(quote! {
+                let (input, _) = ::parse::literal(#lit)(input)?;
+            }),
+            Segment::Field { name, separator } => {
+                let Some(i) = field_names.iter().position(|n| *n == name) else {
+                    return Err(syn::Error::new(
+                        format.span(),
+                        format!("unknown field `{name}`"),
+                    ));
+                };
+                if std::mem::replace(&mut used[i], true) {
+                    return Err(syn::Error::new(
+                        format.span(),
+                        format!("field `{name}` is used more than once"),
+                    ));
+                }
+                let var = field_var(&name);
+                let ty = &fields.iter().nth(i).expect("Field by index").ty;
+                steps.push(match separator {
+                    None => quote! {
+                        let (input, #var) = <#ty as ::parse::AocParse>::parse_nom(input)?;
+                    },
+                    Some(separator) => {
+                        let element = element_type(ty)?;
+                        quote! {
+                            let (input, #var) = ::parse::list(
+                                #separator,
+                                <#element as ::parse::AocParse>::parse_nom,
+                            )(input)?;
+                            let #var = #var.into_iter().collect::<#ty>();
+                        }
+                    }
+                });
+            }
+        }
+    }
+    if let Some(i) = used.iter().position(|u| !u) {
+        return Err(syn::Error::new(
+            format.span(),
+            format!("field `{}` is missing in the format", field_names[i]),
+        ));
+    }
+    let vars = field_names.iter().map(|name| field_var(name));
+    let value = match fields {
+        Fields::Named(_) => {
+            let idents = fields.iter().filter_map(|f| f.ident.as_ref());
+            quote!(#ctor { #(#idents: #vars),* })
+        }
+        Fields::Unnamed(_) => quote!(#ctor ( #(#vars),* )),
+        Fields::Unit => quote!(#ctor),
+    };
+    Ok(quote! {
+        #(#steps)*
+        Ok((input, #value))
+    })
+}
+
+// prefixed, so fields can't shadow the generated bindings (e.g. a field named `input`)
+fn field_var(name: &str) -> Ident {
+    format_ident!("__aoc_{}", name.trim_start_matches("r#"))
+}
+
+fn element_type(ty: &Type) -> syn::Result<&Type> {
+    if let Type::Path(path) = ty {
+        if let Some(PathArguments::AngleBracketed(args)) =
+            path.path.segments.last().map(|s| &s.arguments)
+        {
+            if let Some(GenericArgument::Type(element)) = args.args.first() {
+                return Ok(element);
+            }
+        }
+    }
+    Err(syn::Error::new(
+        ty.span(),
+        "a list field has to be a collection with the element type as its first generic argument",
+    ))
+}
+
+fn parse_format(format: &str) -> Result<Vec<Segment>, String> {
+    let mut segments = Vec::new();
+    let mut literal = String::new();
+    let mut chars = format.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut field = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => field.push(c),
+                        None => return Err(format!("unclosed field `{{{field}`")),
+                    }
+                }
+                let (name, separator) = match field.split_once(':') {
+                    Some((name, "")) => {
+                        return Err(format!("empty separator for field `{name}`"));
+                    }
+                    Some((name, separator)) => (name, Some(separator.to_string())),
+                    None => (field.as_str(), None),
+                };
+                if name.is_empty() {
+                    return Err("empty field name".to_string());
+                }
+                push_literal(&mut segments, &mut literal);
+                segments.push(Segment::Field {
+                    name: name.trim().to_string(),
+                    separator,
+                });
+            }
+            '}' => return Err("unmatched `}`, use `}}` to match a literal `}`".to_string()),
+            c => literal.push(c),
+        }
+    }
+    push_literal(&mut segments, &mut literal);
+    Ok(segments)
+}
+
+// splits the literal into runs of spaces and the rest so spaces can be matched loosely
+fn push_literal(segments: &mut Vec<Segment>, literal: &mut String) {
+    let mut run = String::new();
+    for c in literal.drain(..) {
+        if !run.is_empty() && run.ends_with(' ') != (c == ' ') {
+            segments.push(Segment::Literal(std::mem::take(&mut run)));
+        }
+        run.push(c);
+    }
+    if !run.is_empty() {
+        segments.push(Segment::Literal(run));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(name: &str, separator: Option<&str>) -> Segment {
+        Segment::Field {
+            name: name.to_string(),
+            separator: separator.map(ToString::to_string),
+        }
+    }
+
+    fn lit(literal: &str) -> Segment {
+        Segment::Literal(literal.to_string())
+    }
+
+    #[test]
+    fn format_segments() {
+        assert_eq!(
+            Ok(vec![
+                field("total", None),
+                lit(":"),
+                lit(" "),
+                field("nums", Some(" "))
+            ]),
+            parse_format("{total}: {nums: }")
+        );
+    }
+
+    #[test]
+    fn format_escapes() {
+        assert_eq!(
+            Ok(vec![lit("{"), field("0", Some(",")), lit("}")]),
+            parse_format("{{{0:,}}}")
+        );
+    }
+
+    #[test]
+    fn format_errors() {
+        assert!(parse_format("{a").is_err());
+        assert!(parse_format("a}").is_err());
+        assert!(parse_format("{}").is_err());
+        assert!(parse_format("{a:}").is_err());
+    }
+}
@@ -0,0 +1,155 @@
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alphanumeric1, anychar, space1},
+    combinator::map,
+    multi::separated_list1,
+    IResult, Parser,
+};
+
+use crate::{parse_all_within, NomInt};
+
+/// Types parseable from puzzle input, usually implemented with `#[derive(AocParse)]`.
+pub trait AocParse: Sized {
+    #[allow(clippy::missing_errors_doc)]
+    fn parse_nom(input: &str) -> IResult<&str, Self>;
+
+    /// Parses the whole `input` (apart from trailing whitespace).
+    #[allow(clippy::missing_errors_doc)]
+    fn parse_str(input: &str) -> anyhow::Result<Self> {
+        parse_all_within(input, input, Self::parse_nom)
+    }
+
+    /// Parses every non-empty line, errors point at the line/column within the `input`.
+    #[allow(clippy::missing_errors_doc)]
+    fn parse_lines(input: &str) -> anyhow::Result<Vec<Self>> {
+        input
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(|l| parse_all_within(input, l, Self::parse_nom))
+            .collect()
+    }
+}
+
+impl<T: NomInt> AocParse for T {
+    fn parse_nom(input: &str) -> IResult<&str, Self> {
+        T::parse_int(input)
+    }
+}
+
+impl AocParse for char {
+    fn parse_nom(input: &str) -> IResult<&str, Self> {
+        anychar(input)
+    }
+}
+
+impl AocParse for String {
+    fn parse_nom(input: &str) -> IResult<&str, Self> {
+        map(alphanumeric1, str::to_string)(input)
+    }
+}
+
+/// Matches a format literal, runs of spaces match any (non-empty) amount of spaces/tabs.
+pub fn literal<'a>(literal: &'a str) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str> {
+    move |input| {
+        if !literal.is_empty() && literal.chars().all(|c| c == ' ') {
+            space1(input)
+        } else {
+            tag(literal)(input)
+        }
+    }
+}
+
+/// Parses a non-empty list of elements separated by the `separator` [`literal`].
+pub fn list<'a, O>(
+    separator: &'a str,
+    element: impl Parser<&'a str, O, nom::error::Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(literal(separator), element)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AocParse, ParseError};
+
+    #[derive(Debug, PartialEq, AocParse)]
+    #[aoc(format = "{total}: {nums: }")]
+    struct Equation {
+        total: u64,
+        nums: Vec<u64>,
+    }
+
+    #[derive(Debug, PartialEq, AocParse)]
+    #[aoc(format = "{0}|{1}")]
+    struct Rule(u32, u32);
+
+    #[derive(Debug, PartialEq, AocParse)]
+    #[aoc(format = "{input} -> {r#type}")]
+    struct Wire {
+        input: u32,
+        r#type: char,
+    }
+
+    #[derive(Debug, PartialEq, AocParse)]
+    enum Instruction {
+        #[aoc(format = "mul({0},{1})")]
+        Mul(u32, u32),
+        #[aoc(format = "do()")]
+        Do,
+        #[aoc(format = "don't()")]
+        Dont,
+        #[aoc(format = "set {{{reg}}} {val}")]
+        Set { reg: char, val: i8 },
+    }
+
+    #[test]
+    fn struct_named() {
+        assert_eq!(
+            Equation {
+                total: 190,
+                nums: vec![10, 19]
+            },
+            Equation::parse_str("190: 10 19").unwrap()
+        );
+    }
+
+    #[test]
+    fn struct_tuple_lines() {
+        assert_eq!(
+            vec![Rule(47, 53), Rule(97, 13)],
+            Rule::parse_lines("47|53\n97|13\n").unwrap()
+        );
+    }
+
+    #[test]
+    fn fields_named_like_bindings() {
+        assert_eq!(
+            Wire {
+                input: 3,
+                r#type: 'x'
+            },
+            Wire::parse_str("3 -> x").unwrap()
+        );
+    }
+
+    #[test]
+    fn enum_variants() {
+        assert_eq!(
+            vec![
+                Instruction::Mul(2, 4),
+                Instruction::Dont,
+                Instruction::Do,
+                Instruction::Set { reg: 'a', val: -3 }
+            ],
+            Instruction::parse_lines("mul(2,4)\ndon't()\ndo()\nset {a} -3").unwrap()
+        );
+    }
+
+    #[test]
+    fn error_line() {
+        let err = Equation::parse_lines("190: 10 19\n3267: 81 x 27")
+            .unwrap_err()
+            .downcast::<ParseError>()
+            .unwrap();
+        assert_eq!((2, 9), (err.line, err.column));
+    }
+}
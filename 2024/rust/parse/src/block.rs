@@ -0,0 +1,39 @@
+use nom::{
+    character::complete::line_ending, multi::separated_list1, sequence::pair, IResult, Parser,
+};
+
+/// Parses a non-empty list of blocks separated by a single blank line.
+pub fn blocks<'a, O>(
+    block: impl Parser<&'a str, O, nom::error::Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(pair(line_ending, line_ending), block)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::int_list;
+    use nom::bytes::complete::tag;
+
+    #[test]
+    fn int_blocks() {
+        let input = "1,2\n3\n\n4\n\n5,6,7";
+        let (rest, res) =
+            blocks(separated_list1(line_ending, int_list::<u8, _>(tag(","))))(input).unwrap();
+        assert_eq!("", rest);
+        assert_eq!(
+            vec![
+                vec![vec![1, 2], vec![3]],
+                vec![vec![4]],
+                vec![vec![5, 6, 7]]
+            ],
+            res
+        );
+    }
+
+    #[test]
+    fn crlf_blocks() {
+        let (_, res) = blocks(int_list::<u8, _>(tag(",")))("1,2\r\n\r\n3").unwrap();
+        assert_eq!(vec![vec![1, 2], vec![3]], res);
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt::{self, Display};
+
+use nom::{error::ErrorKind, IResult, Parser};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ErrorKind,
+    pub line_content: String,
+}
+impl ParseError {
+    #[must_use]
+    pub fn new(input: &str, remaining: &str, kind: ErrorKind) -> Self {
+        let offset = Self::offset(input, remaining);
+        let consumed = &input[..offset];
+        let line = consumed.matches('\n').count() + 1;
+        let line_start = consumed.rfind('\n').map_or(0, |i| i + 1);
+        let column = consumed[line_start..].chars().count() + 1;
+        let line_content = input[line_start..]
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        Self {
+            line,
+            column,
+            kind,
+            line_content,
+        }
+    }
+
+    // `remaining` is usually a suffix of `input`, but it can also point into a line parsed from it
+    fn offset(input: &str, remaining: &str) -> usize {
+        let start = input.as_ptr() as usize;
+        let pos = remaining.as_ptr() as usize;
+        if (start..=start + input.len()).contains(&pos) {
+            pos - start
+        } else {
+            input.len().saturating_sub(remaining.len())
+        }
+    }
+}
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Parse error ({:?}) at {}:{}\n{}\n{:>column$}",
+            self.kind,
+            self.line,
+            self.column,
+            self.line_content,
+            '^',
+            column = self.column
+        )
+    }
+}
+impl std::error::Error for ParseError {}
+
+/// Converts a nom error into an `anyhow::Error` with the line/column relative to the full `input`.
+#[must_use]
+pub fn to_anyhow(input: &str, err: nom::Err<nom::error::Error<&str>>) -> anyhow::Error {
+    match err {
+        nom::Err::Incomplete(needed) => anyhow::anyhow!("Incomplete input: {needed:?}"),
+        nom::Err::Error(e) | nom::Err::Failure(e) => ParseError::new(input, e.input, e.code).into(),
+    }
+}
+
+pub trait IResultExt<'a, O> {
+    /// Drops the remaining input and converts the error with [`to_anyhow`].
+    #[allow(clippy::missing_errors_doc)]
+    fn into_anyhow(self, input: &'a str) -> anyhow::Result<O>;
+}
+impl<'a, O> IResultExt<'a, O> for IResult<&'a str, O> {
+    fn into_anyhow(self, input: &'a str) -> anyhow::Result<O> {
+        self.map(|(_, out)| out).map_err(|e| to_anyhow(input, e))
+    }
+}
+
+/// Runs the parser and fails unless the whole input (apart from trailing whitespace) is consumed.
+#[allow(clippy::missing_errors_doc)]
+pub fn parse_all<'a, O>(
+    input: &'a str,
+    parser: impl Parser<&'a str, O, nom::error::Error<&'a str>>,
+) -> anyhow::Result<O> {
+    parse_all_within(input, input, parser)
+}
+
+/// Like [`parse_all`], but parses a `slice` of the `input` and reports errors relative to the `input`.
+#[allow(clippy::missing_errors_doc)]
+pub fn parse_all_within<'a, O>(
+    input: &str,
+    slice: &'a str,
+    mut parser: impl Parser<&'a str, O, nom::error::Error<&'a str>>,
+) -> anyhow::Result<O> {
+    let (rest, out) = parser.parse(slice).map_err(|e| to_anyhow(input, e))?;
+    if rest.trim().is_empty() {
+        Ok(out)
+    } else {
+        Err(ParseError::new(input, rest, ErrorKind::Eof).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::character::complete;
+    use test_case::test_case;
+
+    #[test_case("12\n34\nx6", 2, 3)]
+    #[test_case("12\n34 x6", 2, 3)]
+    #[test_case("x", 1, 1)]
+    fn error_position(input: &str, line: usize, column: usize) {
+        let err = parse_all(
+            input,
+            nom::multi::separated_list1(complete::multispace1, complete::u32),
+        )
+        .unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!((line, column), (err.line, err.column));
+    }
+
+    #[test]
+    fn parse_all_trailing_whitespace() {
+        assert_eq!(42, parse_all("42\n", complete::u8).unwrap());
+    }
+
+    #[test]
+    fn within_line() {
+        let input = "1 2\n3 4\n5 x";
+        let line = input.lines().nth(2).unwrap();
+        let err = parse_all_within(
+            input,
+            line,
+            nom::multi::separated_list1(complete::space1, complete::u8),
+        )
+        .unwrap_err()
+        .downcast::<ParseError>()
+        .unwrap();
+        assert_eq!(
+            (3, 2, "5 x"),
+            (err.line, err.column, err.line_content.as_str())
+        );
+    }
+
+    #[test]
+    fn into_anyhow_msg() {
+        let input = "1,2\n3,x";
+        let err = nom::sequence::preceded(complete::u8, complete::char(','))(&input[6..])
+            .into_anyhow(input)
+            .unwrap_err();
+        assert!(err.to_string().contains("at 2:3"));
+        assert!(err.to_string().contains("3,x"));
+    }
+}
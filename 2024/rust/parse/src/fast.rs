@@ -0,0 +1,181 @@
+//! Allocation free integer scanning over raw input bytes.
+//!
+//! Non-digit bytes are skipped 8 at a time (SWAR), so sparse inputs don't pay for a per-byte branch.
+
+use std::marker::PhantomData;
+
+const LANE_ONES: u64 = u64::MAX / 255;
+const LANE_HIGH_BITS: u64 = LANE_ONES * 128;
+const LANE_LOW_BITS: u64 = LANE_ONES * 127;
+
+pub trait FastInt: Copy {
+    const SIGNED: bool;
+
+    /// Builds the value from the digit accumulator, overflow wraps.
+    fn from_digits(value: u64, negative: bool) -> Self;
+}
+
+macro_rules! impl_fast_int {
+    ($signed: literal, $($int: ty),*) => {
+        $(
+            impl FastInt for $int {
+                const SIGNED: bool = $signed;
+
+                #[inline]
+                fn from_digits(value: u64, negative: bool) -> Self {
+                    let value = value as $int;
+                    if negative {
+                        value.wrapping_neg()
+                    } else {
+                        value
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_fast_int!(false, u8, u16, u32, u64, usize);
+impl_fast_int!(true, i8, i16, i32, i64, isize);
+
+/// Iterator over all integers in the bytes, a `-` directly before the digits negates signed integers.
+pub struct Ints<'a, T> {
+    bytes: &'a [u8],
+    pos: usize,
+    int: PhantomData<T>,
+}
+impl<T: FastInt> Iterator for Ints<'_, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = find_digit(self.bytes, self.pos)?;
+        let negative = T::SIGNED && start > 0 && self.bytes[start - 1] == b'-';
+        let mut value = 0u64;
+        let mut pos = start;
+        while let Some(digit) = self.bytes.get(pos).map(|b| b.wrapping_sub(b'0')) {
+            if digit > 9 {
+                break;
+            }
+            value = value.wrapping_mul(10).wrapping_add(u64::from(digit));
+            pos += 1;
+        }
+        self.pos = pos;
+        Some(T::from_digits(value, negative))
+    }
+}
+
+/// Scans the bytes (e.g. a single line or the whole input) for integers.
+#[must_use]
+pub fn ints<T: FastInt>(bytes: &[u8]) -> Ints<'_, T> {
+    Ints {
+        bytes,
+        pos: 0,
+        int: PhantomData,
+    }
+}
+
+/// Returns the first `N` integers of the bytes or `None` if there are fewer.
+#[must_use]
+pub fn ints_array<T: FastInt + Default, const N: usize>(bytes: &[u8]) -> Option<[T; N]> {
+    let mut res = [T::default(); N];
+    let mut ints = ints(bytes);
+    for val in &mut res {
+        *val = ints.next()?;
+    }
+    Some(res)
+}
+
+/// Iterates over the non-empty lines of the bytes (a trailing `\r` is not stripped as it's skipped by [`ints`]).
+pub fn lines(bytes: &[u8]) -> impl Iterator<Item = &[u8]> {
+    bytes.split(|b| *b == b'\n').filter(|l| !l.is_empty())
+}
+
+/// Index of the first ASCII digit at or after `from`.
+#[inline]
+fn find_digit(bytes: &[u8], from: usize) -> Option<usize> {
+    let mut pos = from;
+    while let Some(chunk) = bytes.get(pos..pos + 8) {
+        let lanes = u64::from_le_bytes(chunk.try_into().expect("8 byte chunk"));
+        if has_digit(lanes) {
+            break;
+        }
+        pos += 8;
+    }
+    bytes
+        .get(pos..)?
+        .iter()
+        .position(u8::is_ascii_digit)
+        .map(|i| pos + i)
+}
+
+// 'hasbetween' from the bit twiddling hacks, flags lanes in the (b'0' - 1, b'9' + 1) range
+#[inline]
+const fn has_digit(lanes: u64) -> bool {
+    const LOW: u64 = b'0' as u64 - 1;
+    const HIGH: u64 = b'9' as u64 + 1;
+    let low_bits = lanes & LANE_LOW_BITS;
+    (LANE_ONES * (127 + HIGH)).wrapping_sub(low_bits)
+        & !lanes
+        & low_bits.wrapping_add(LANE_ONES * (127 - LOW))
+        & LANE_HIGH_BITS
+        != 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("3   4\n4   3" => vec![3, 4, 4, 3])]
+    #[test_case("p=0,4 v=3,-3" => vec![0, 4, 3, -3])]
+    #[test_case("Button A: X+94, Y+34" => vec![94, 34])]
+    #[test_case("-1--2 - 3" => vec![-1, -2, 3])]
+    #[test_case("no digits here at all!" => Vec::<i64>::new())]
+    #[test_case("" => Vec::<i64>::new())]
+    #[test_case("ümlaut 12 ñ -7 ☃☃☃☃☃☃ 123456789012" => vec![12, -7, 123_456_789_012])]
+    fn signed(input: &str) -> Vec<i64> {
+        ints(input.as_bytes()).collect()
+    }
+
+    #[test]
+    fn unsigned_ignores_sign() {
+        assert_eq!(vec![1, 2], ints::<u32>(b"x=-1,y=2").collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn matches_str_parse() {
+        let input = (0..500_i64)
+            .flat_map(|i| [i * 7919, -i * 31, i % 3])
+            .map(|n| format!("{n} ,_"))
+            .collect::<Vec<_>>()
+            .join("\r\n");
+        let expected: Vec<i64> = input
+            .split(|c: char| !c.is_ascii_digit() && c != '-')
+            .flat_map(str::parse)
+            .collect();
+        assert_eq!(expected, ints::<i64>(input.as_bytes()).collect::<Vec<_>>());
+    }
+
+    #[test_case("1 2 3" => Some([1, 2]))]
+    #[test_case("1" => None)]
+    fn array(input: &str) -> Option<[u8; 2]> {
+        ints_array(input.as_bytes())
+    }
+
+    #[test]
+    fn lines_ints() {
+        let sums: Vec<u32> = lines(b"1 2\r\n\n3 4\n")
+            .map(|l| ints::<u32>(l).sum())
+            .collect();
+        assert_eq!(vec![3, 7], sums);
+    }
+
+    #[test]
+    fn swar_digit_lanes() {
+        for b in 0..=u8::MAX {
+            let lanes = u64::from_le_bytes([b'x', b'y', b, 0, 255, 128, b'/', b':']);
+            assert_eq!(b.is_ascii_digit(), has_digit(lanes), "byte {b}");
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use nom::{
+    bytes::complete::{tag, take_until},
+    character::complete::alphanumeric1,
+    sequence::{separated_pair, terminated},
+    IResult, Parser,
+};
+
+/// Parses a `label: ` prefix (e.g. `Button A: `) and returns the label.
+#[allow(clippy::missing_errors_doc)]
+pub fn label(input: &str) -> IResult<&str, &str> {
+    terminated(take_until(": "), tag(": "))(input)
+}
+
+/// Parses an alphanumeric key and a value separated by `separator` (e.g. `p=0,4`).
+pub fn key_value<'a, O>(
+    separator: &'a str,
+    value: impl Parser<&'a str, O, nom::error::Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (&'a str, O)> {
+    separated_pair(alphanumeric1, tag(separator), value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::int;
+    use test_case::test_case;
+
+    #[test_case("Button A: X+94" => ("Button A", "X+94"))]
+    #[test_case("Prize: X=8400" => ("Prize", "X=8400"))]
+    fn label_prefix(input: &str) -> (&str, &str) {
+        let (rest, label) = label(input).unwrap();
+        (label, rest)
+    }
+
+    #[test_case("a=5" => ("a", 5))]
+    #[test_case("reg1=42" => ("reg1", 42))]
+    fn key_eq_value(input: &str) -> (&str, u32) {
+        key_value("=", int)(input).unwrap().1
+    }
+}
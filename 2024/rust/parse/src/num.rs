@@ -0,0 +1,70 @@
+use nom::{
+    character::complete::{self, space1},
+    multi::separated_list1,
+    IResult, Parser,
+};
+
+pub trait NomInt: Sized {
+    #[allow(clippy::missing_errors_doc)]
+    fn parse_int(input: &str) -> IResult<&str, Self>;
+}
+
+macro_rules! impl_nom_int {
+    ($($int: ident),*) => {
+        $(
+            impl NomInt for $int {
+                fn parse_int(input: &str) -> IResult<&str, Self> {
+                    complete::$int(input)
+                }
+            }
+        )*
+    };
+}
+
+impl_nom_int!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+/// Parses a single (optionally signed for signed types) integer.
+#[allow(clippy::missing_errors_doc)]
+pub fn int<T: NomInt>(input: &str) -> IResult<&str, T> {
+    T::parse_int(input)
+}
+
+/// Parses a non-empty list of integers separated by `separator`.
+pub fn int_list<'a, T: NomInt, S>(
+    separator: impl Parser<&'a str, S, nom::error::Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    separated_list1(separator, int)
+}
+
+/// Parses a non-empty list of integers separated by spaces/tabs.
+#[allow(clippy::missing_errors_doc)]
+pub fn int_list_ws<T: NomInt>(input: &str) -> IResult<&str, Vec<T>> {
+    int_list(space1)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::bytes::complete::tag;
+    use test_case::test_case;
+
+    #[test_case("1,2,3" => vec![1, 2, 3])]
+    #[test_case("-1,+2,-30" => vec![-1, 2, -30])]
+    #[test_case("7" => vec![7])]
+    fn signed_list(input: &str) -> Vec<i32> {
+        int_list(tag(","))(input).unwrap().1
+    }
+
+    #[test]
+    fn unsigned_list_rejects_sign() {
+        let (rest, nums) = int_list::<u64, _>(tag(", "))("4, 5, -6").unwrap();
+        assert_eq!(vec![4, 5], nums);
+        assert_eq!(", -6", rest);
+    }
+
+    #[test_case("75 47   61\t53" => vec![75, 47, 61, 53])]
+    #[test_case("1" => vec![1])]
+    fn ws_list(input: &str) -> Vec<u8> {
+        int_list_ws(input).unwrap().1
+    }
+}
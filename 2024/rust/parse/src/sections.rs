@@ -0,0 +1,200 @@
+use anyhow::{anyhow, bail, Context};
+
+/// Iterator over the sections of an input, blank (or whitespace only) lines separate the sections.
+///
+/// The yielded sections are slices of the input without the trailing line ending,
+/// so they can be split further with `lines()` (which also handles `\r\n`).
+pub struct Sections<'a> {
+    input: &'a str,
+    offset: usize,
+    max_height: Option<usize>,
+}
+impl<'a> Sections<'a> {
+    fn next_line(&mut self) -> Option<(usize, &'a str)> {
+        let rest = &self.input[self.offset..];
+        if rest.is_empty() {
+            return None;
+        }
+        let len = rest.find('\n').map_or(rest.len(), |i| i + 1);
+        let start = self.offset;
+        self.offset += len;
+        Some((start, &rest[..len]))
+    }
+
+    /// The 1-based line number of the `section` start within the input.
+    #[must_use]
+    pub fn line_of(&self, section: &str) -> usize {
+        let offset = section.as_ptr() as usize - self.input.as_ptr() as usize;
+        self.input[..offset].matches('\n').count() + 1
+    }
+}
+impl<'a> Iterator for Sections<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, mut line) = loop {
+            let (start, line) = self.next_line()?;
+            if !line.trim().is_empty() {
+                break (start, line);
+            }
+        };
+        let mut end = start;
+        let mut height = 1;
+        loop {
+            end += line.trim_end_matches(['\r', '\n']).len();
+            if self.max_height.is_some_and(|max| height >= max) {
+                break;
+            }
+            let offset = self.offset;
+            match self.next_line() {
+                Some((line_start, next)) if !next.trim().is_empty() => {
+                    end = line_start;
+                    line = next;
+                    height += 1;
+                }
+                _ => {
+                    self.offset = offset;
+                    break;
+                }
+            }
+        }
+        Some(&self.input[start..end])
+    }
+}
+
+/// Splits the input into sections separated by blank lines.
+#[must_use]
+pub fn sections(input: &str) -> Sections<'_> {
+    Sections {
+        input,
+        offset: 0,
+        max_height: None,
+    }
+}
+
+/// Splits the input into chunks of (at most) `height` lines, blank lines also end a chunk and are skipped.
+#[must_use]
+#[allow(clippy::missing_panics_doc)]
+pub fn chunks(input: &str, height: usize) -> Sections<'_> {
+    assert!(height > 0, "Chunk height has to be non-zero");
+    Sections {
+        input,
+        offset: 0,
+        max_height: Some(height),
+    }
+}
+
+pub trait SectionParsers<'a> {
+    type Output;
+
+    #[allow(clippy::missing_errors_doc)]
+    fn parse_sections(self, sections: &mut Sections<'a>) -> anyhow::Result<Self::Output>;
+}
+
+macro_rules! impl_section_parsers {
+    ($($parser: ident => $out: ident),+) => {
+        impl<'a, $($parser, $out),+> SectionParsers<'a> for ($($parser,)+)
+        where
+            $($parser: FnOnce(&'a str) -> anyhow::Result<$out>),+
+        {
+            type Output = ($($out,)+);
+
+            #[allow(non_snake_case)]
+            fn parse_sections(self, sections: &mut Sections<'a>) -> anyhow::Result<Self::Output> {
+                let ($($parser,)+) = self;
+                let mut index = 0;
+                Ok(($({
+                    index += 1;
+                    let section = sections
+                        .next()
+                        .ok_or_else(|| anyhow!("Missing input section #{index}"))?;
+                    $parser(section).with_context(|| {
+                        format!(
+                            "Invalid input section #{index} starting at line {}",
+                            sections.line_of(section)
+                        )
+                    })?
+                },)+))
+            }
+        }
+    };
+}
+
+impl_section_parsers!(A => OA);
+impl_section_parsers!(A => OA, B => OB);
+impl_section_parsers!(A => OA, B => OB, C => OC);
+impl_section_parsers!(A => OA, B => OB, C => OC, D => OD);
+impl_section_parsers!(A => OA, B => OB, C => OC, D => OD, E => OE);
+
+/// Parses each blank line separated section with the matching parser from the `parsers` tuple.
+///
+/// Fails if the section count doesn't match the parser count.
+#[allow(clippy::missing_errors_doc)]
+pub fn parse_sections<'a, P: SectionParsers<'a>>(
+    input: &'a str,
+    parsers: P,
+) -> anyhow::Result<P::Output> {
+    let mut sections = sections(input);
+    let output = parsers.parse_sections(&mut sections)?;
+    if let Some(section) = sections.next() {
+        bail!(
+            "Unexpected input section starting at line {}",
+            sections.line_of(section)
+        );
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AocParse, ParseError};
+    use test_case::test_case;
+
+    #[test_case("a\nb\n\nc" => vec!["a\nb", "c"])]
+    #[test_case("\n\na\n \t\n\n\nb  \n  \n" => vec!["a", "b  "])]
+    #[test_case("a\r\nb\r\n\r\nc\r\n" => vec!["a\r\nb", "c"])]
+    #[test_case("a" => vec!["a"])]
+    #[test_case("\n  \n" => Vec::<&str>::new())]
+    fn split(input: &str) -> Vec<&str> {
+        sections(input).collect()
+    }
+
+    #[test_case("1\n2\n3\n4\n5", 2 => vec!["1\n2", "3\n4", "5"])]
+    #[test_case("1\n2\n\n3\n4\n\n", 2 => vec!["1\n2", "3\n4"])]
+    #[test_case("1\n\n2\n3", 3 => vec!["1", "2\n3"])]
+    fn chunked(input: &str, height: usize) -> Vec<&str> {
+        chunks(input, height).collect()
+    }
+
+    #[derive(Debug, PartialEq, AocParse)]
+    #[aoc(format = "{0}|{1}")]
+    struct Rule(u8, u8);
+
+    #[test]
+    fn typed_sections() {
+        let (rules, updates, tail) = parse_sections(
+            "1|2\n3|4\n\n5,6\n\ntail\n",
+            (Rule::parse_lines, |s: &str| Ok(s.len()), |s| Ok(s)),
+        )
+        .unwrap();
+        assert_eq!(vec![Rule(1, 2), Rule(3, 4)], rules);
+        assert_eq!(3, updates);
+        assert_eq!("tail", tail);
+    }
+
+    #[test]
+    fn section_count_mismatch() {
+        assert!(parse_sections("1|2\n\n3|4", (Rule::parse_lines,)).is_err());
+        assert!(parse_sections("1|2", (Rule::parse_lines, Rule::parse_lines)).is_err());
+    }
+
+    #[test]
+    fn section_error_context() {
+        let err =
+            parse_sections("1|2\n\n3|4\n5-6", (Rule::parse_lines, Rule::parse_lines)).unwrap_err();
+        assert!(err.to_string().contains("section #2 starting at line 3"));
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!((2, 2), (err.line, err.column));
+    }
+}
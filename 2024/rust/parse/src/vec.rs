@@ -0,0 +1,214 @@
+use glam::{
+    I16Vec2, I16Vec3, I16Vec4, I64Vec2, I64Vec3, I64Vec4, I8Vec2, I8Vec3, I8Vec4, IVec2, IVec3,
+    IVec4, U16Vec2, U16Vec3, U16Vec4, U64Vec2, U64Vec3, U64Vec4, U8Vec2, U8Vec3, U8Vec4, UVec2,
+    UVec3, UVec4,
+};
+use nom::{bytes::complete::tag, character::complete::one_of, combinator::opt, IResult};
+
+use crate::{int, literal, AocParse, NomInt};
+
+const AXES: [&str; 4] = ["X", "Y", "Z", "W"];
+
+/// Integer vectors which can be parsed element by element.
+pub trait ParseVec: Sized {
+    type Element: NomInt + Copy + Default;
+    const DIMENSIONS: usize;
+
+    fn from_elements(elements: &[Self::Element]) -> Self;
+
+    /// Parses the vector using the default `x,y[,z[,w]]` [`VecFormat`].
+    #[allow(clippy::missing_errors_doc)]
+    fn parse_vec(input: &str) -> IResult<&str, Self> {
+        vec_with(VecFormat::default())(input)
+    }
+}
+
+macro_rules! impl_parse_vec {
+    ($int: ty, $($vec: ty => $dimensions: literal),+) => {
+        $(
+            impl ParseVec for $vec {
+                type Element = $int;
+                const DIMENSIONS: usize = $dimensions;
+
+                fn from_elements(elements: &[Self::Element]) -> Self {
+                    Self::from_slice(elements)
+                }
+            }
+
+            impl AocParse for $vec {
+                fn parse_nom(input: &str) -> IResult<&str, Self> {
+                    Self::parse_vec(input)
+                }
+            }
+        )+
+    };
+}
+
+impl_parse_vec!(i8, I8Vec2 => 2, I8Vec3 => 3, I8Vec4 => 4);
+impl_parse_vec!(u8, U8Vec2 => 2, U8Vec3 => 3, U8Vec4 => 4);
+impl_parse_vec!(i16, I16Vec2 => 2, I16Vec3 => 3, I16Vec4 => 4);
+impl_parse_vec!(u16, U16Vec2 => 2, U16Vec3 => 3, U16Vec4 => 4);
+impl_parse_vec!(i32, IVec2 => 2, IVec3 => 3, IVec4 => 4);
+impl_parse_vec!(u32, UVec2 => 2, UVec3 => 3, UVec4 => 4);
+impl_parse_vec!(i64, I64Vec2 => 2, I64Vec3 => 3, I64Vec4 => 4);
+impl_parse_vec!(u64, U64Vec2 => 2, U64Vec3 => 3, U64Vec4 => 4);
+
+/// Describes how vector elements are separated and what surrounds them, e.g. `p=<1, 2, 3>`.
+///
+/// The separator is matched as a [`literal`], so spaces match any run of spaces/tabs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VecFormat<'a> {
+    pub separator: &'a str,
+    pub label: Option<&'a str>,
+    pub brackets: Option<(&'a str, &'a str)>,
+}
+impl Default for VecFormat<'_> {
+    fn default() -> Self {
+        Self {
+            separator: ",",
+            label: None,
+            brackets: None,
+        }
+    }
+}
+impl<'a> VecFormat<'a> {
+    #[must_use]
+    pub const fn separator(mut self, separator: &'a str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    #[must_use]
+    pub const fn label(mut self, label: &'a str) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    #[must_use]
+    pub const fn brackets(mut self, open: &'a str, close: &'a str) -> Self {
+        self.brackets = Some((open, close));
+        self
+    }
+}
+
+/// Parses a vector in the default `x,y[,z[,w]]` format.
+#[allow(clippy::missing_errors_doc)]
+pub fn vec<V: ParseVec>(input: &str) -> IResult<&str, V> {
+    V::parse_vec(input)
+}
+
+/// Parses a vector described by the `format`.
+pub fn vec_with<'a, V: ParseVec>(
+    format: VecFormat<'a>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, V> {
+    move |mut input| {
+        if let Some(label) = format.label {
+            (input, _) = tag(label)(input)?;
+        }
+        if let Some((open, _)) = format.brackets {
+            (input, _) = tag(open)(input)?;
+        }
+        let mut elements = [V::Element::default(); 4];
+        for (i, element) in elements.iter_mut().take(V::DIMENSIONS).enumerate() {
+            if i > 0 {
+                (input, _) = literal(format.separator)(input)?;
+            }
+            (input, *element) = int(input)?;
+        }
+        if let Some((_, close)) = format.brackets {
+            (input, _) = tag(close)(input)?;
+        }
+        Ok((input, V::from_elements(&elements[..V::DIMENSIONS])))
+    }
+}
+
+/// Parses a `x,y[,z[,w]]` vector preceded by the `label` (e.g. `v=3,-3`).
+pub fn labelled_vec<'a, V: ParseVec>(label: &'a str) -> impl FnMut(&'a str) -> IResult<&'a str, V> {
+    vec_with(VecFormat::default().label(label))
+}
+
+/// Parses an `X+94, Y=34` style vector where each axis is followed by an optional `+`/`=`.
+#[allow(clippy::missing_errors_doc)]
+pub fn axis_vec<V: ParseVec>(mut input: &str) -> IResult<&str, V> {
+    let mut elements = [V::Element::default(); 4];
+    for (i, element) in elements.iter_mut().take(V::DIMENSIONS).enumerate() {
+        if i > 0 {
+            (input, _) = tag(", ")(input)?;
+        }
+        (input, _) = tag(AXES[i])(input)?;
+        (input, _) = opt(one_of("+="))(input)?;
+        (input, *element) = int(input)?;
+    }
+    Ok((input, V::from_elements(&elements[..V::DIMENSIONS])))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::label;
+    use nom::{
+        character::complete::space1,
+        sequence::{pair, separated_pair},
+    };
+    use test_case::test_case;
+
+    #[test_case("1,2" => IVec2::new(1, 2))]
+    #[test_case("-1,-22" => IVec2::new(-1, -22))]
+    fn ivec2(input: &str) -> IVec2 {
+        vec(input).unwrap().1
+    }
+
+    #[test_case("1,2,3" => I64Vec3::new(1, 2, 3))]
+    #[test_case("-10000000000,0,7" => I64Vec3::new(-10_000_000_000, 0, 7))]
+    fn i64vec3(input: &str) -> I64Vec3 {
+        vec(input).unwrap().1
+    }
+
+    #[test]
+    fn u8vec4() {
+        assert_eq!(
+            U8Vec4::new(1, 2, 3, 4),
+            U8Vec4::parse_vec("1,2,3,4").unwrap().1
+        );
+    }
+
+    #[test]
+    fn unsigned_rejects_sign() {
+        assert!(vec::<UVec3>("1,-2,3").is_err());
+    }
+
+    #[test_case(VecFormat::default().separator(" "), "1   2  3")]
+    #[test_case(VecFormat::default().separator(", ").brackets("<", ">"), "<1, 2, 3>")]
+    #[test_case(VecFormat::default().separator(", ").brackets("(", ")").label("pos="), "pos=(1, 2, 3)")]
+    #[test_case(VecFormat::default().separator(" @ "), "1 @ 2 @ 3")]
+    fn formats(format: VecFormat, input: &str) {
+        let (rest, vec): (_, IVec3) = vec_with(format)(input).unwrap();
+        assert_eq!("", rest);
+        assert_eq!(IVec3::new(1, 2, 3), vec);
+    }
+
+    #[test]
+    fn missing_bracket() {
+        assert!(vec_with::<IVec2>(VecFormat::default().brackets("<", ">"))("<1,2").is_err());
+    }
+
+    #[test_case("Button A: X+94, Y+34" => ("Button A", U64Vec2::new(94, 34)))]
+    #[test_case("Prize: X=8400, Y=5400" => ("Prize", U64Vec2::new(8400, 5400)))]
+    fn labelled_axis_vec(input: &str) -> (&str, U64Vec2) {
+        pair(label, axis_vec)(input).unwrap().1
+    }
+
+    #[test]
+    fn signed_axis_vec() {
+        let (_, vec): (_, IVec3) = axis_vec("X-3, Y=-7, Z+2").unwrap();
+        assert_eq!(IVec3::new(-3, -7, 2), vec);
+    }
+
+    #[test]
+    fn robot() {
+        let (_, (p, v)): (_, (IVec2, IVec2)) =
+            separated_pair(labelled_vec("p="), space1, labelled_vec("v="))("p=0,4 v=3,-3").unwrap();
+        assert_eq!(IVec2::new(0, 4), p);
+        assert_eq!(IVec2::new(3, -3), v);
+    }
+}
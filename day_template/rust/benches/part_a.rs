@@ -4,7 +4,7 @@ use day_{{day}}::solution::part_a;
 use std::hint::black_box;
 
 fn part_a_benchmark(c: &mut Criterion) {
-    let input = block_on_input({{day}});
+    let input = block_on_input(None, {{day}});
     let mut group = c.benchmark_group("day-{{day}}");
     // group
     //     .sample_size(10)